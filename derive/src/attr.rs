@@ -0,0 +1,93 @@
+use syn::{Attribute, Data, DeriveInput, Field, Fields, LitStr, Result};
+
+/// Parsed `#[rdbc(...)]` field attributes.
+#[derive(Default)]
+pub struct FieldAttrs {
+    pub rename: Option<String>,
+    pub default: bool,
+    pub flatten: bool,
+    pub skip: bool,
+}
+
+impl FieldAttrs {
+    pub fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut field_attrs = FieldAttrs::default();
+
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("rdbc")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    let name: LitStr = meta.value()?.parse()?;
+                    field_attrs.rename = Some(name.value());
+                } else if meta.path.is_ident("default") {
+                    field_attrs.default = true;
+                } else if meta.path.is_ident("flatten") {
+                    field_attrs.flatten = true;
+                } else if meta.path.is_ident("skip") {
+                    field_attrs.skip = true;
+                } else {
+                    return Err(meta.error("unsupported rdbc attribute"));
+                }
+
+                Ok(())
+            })?;
+        }
+
+        Ok(field_attrs)
+    }
+}
+
+/// Parsed `#[rdbc(...)]` container attributes.
+#[derive(Default)]
+pub struct ContainerAttrs {
+    pub positional: bool,
+}
+
+impl ContainerAttrs {
+    pub fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut container_attrs = ContainerAttrs::default();
+
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("rdbc")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("positional") {
+                    container_attrs.positional = true;
+                } else {
+                    return Err(meta.error("unsupported rdbc attribute"));
+                }
+
+                Ok(())
+            })?;
+        }
+
+        Ok(container_attrs)
+    }
+}
+
+/// Returns named fields of struct `input`.
+pub fn named_fields(input: &DeriveInput) -> Result<Vec<&Field>> {
+    match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => Ok(fields.named.iter().collect()),
+            _ => Err(syn::Error::new_spanned(
+                &input.ident,
+                "only structs with named fields are supported",
+            )),
+        },
+        _ => Err(syn::Error::new_spanned(
+            &input.ident,
+            "only structs are supported",
+        )),
+    }
+}
+
+/// Column or argument name of `field`.
+pub fn field_name(field: &Field, attrs: &FieldAttrs) -> String {
+    attrs.rename.clone().unwrap_or_else(|| {
+        field
+            .ident
+            .as_ref()
+            .unwrap()
+            .to_string()
+            .trim_start_matches("r#")
+            .to_owned()
+    })
+}
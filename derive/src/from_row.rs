@@ -0,0 +1,56 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Result};
+
+use crate::attr::{field_name, named_fields, FieldAttrs};
+
+pub fn expand(input: DeriveInput) -> Result<TokenStream> {
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let mut reads = vec![];
+
+    for field in named_fields(&input)? {
+        let attrs = FieldAttrs::parse(&field.attrs)?;
+        let field_ident = field.ident.as_ref().unwrap();
+        let ty = &field.ty;
+
+        let read = if attrs.skip {
+            quote!(::std::default::Default::default())
+        } else if attrs.flatten {
+            quote!(<#ty as ::rdbc_rs::FromRow>::from_row(rows).await?)
+        } else {
+            let name = field_name(field, &attrs);
+
+            if attrs.default {
+                quote! {
+                    if rows.contains_column(#name).await? {
+                        rows.get_as::<#ty, _>(#name).await?
+                    } else {
+                        ::std::default::Default::default()
+                    }
+                }
+            } else {
+                quote!(rows.get_as::<#ty, _>(#name).await?)
+            }
+        };
+
+        reads.push(quote!(#field_ident: #read));
+    }
+
+    Ok(quote! {
+        #[::rdbc_rs::async_trait]
+        impl #impl_generics ::rdbc_rs::FromRow for #ident #ty_generics #where_clause {
+            async fn from_row<DB>(
+                rows: &mut ::rdbc_rs::Rows<DB>,
+            ) -> ::std::result::Result<Self, ::rdbc_rs::anyhow::Error>
+            where
+                DB: ::rdbc_rs::ConnectionPool + ::std::marker::Sync + ::std::marker::Send,
+            {
+                ::std::result::Result::Ok(Self {
+                    #(#reads,)*
+                })
+            }
+        }
+    })
+}
@@ -0,0 +1,51 @@
+//! Derive macros for [rdbc-rs](https://docs.rs/rdbc-rs).
+//!
+//! The generated code refers to the `rdbc_rs` crate, enable the *derive*
+//! feature of `rdbc-rs` and use the macros re-exported there.
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+mod attr;
+mod from_row;
+mod to_arguments;
+
+/// Derive `rdbc_rs::FromRow`, reading each field from the column with the same name.
+///
+/// Field attributes:
+///
+/// * `#[rdbc(rename = "col")]` - read from column `col` instead of the field name.
+/// * `#[rdbc(default)]` - use [`Default::default`] if the result set has no such column.
+/// * `#[rdbc(flatten)]` - read the field with its own `FromRow` implementation.
+/// * `#[rdbc(skip)]` - do not read the field, use [`Default::default`].
+#[proc_macro_derive(FromRow, attributes(rdbc))]
+pub fn derive_from_row(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    from_row::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive `rdbc_rs::driver::ToArguments`, binding each field as a named argument.
+///
+/// Container attributes:
+///
+/// * `#[rdbc(positional)]` - bind fields by position `1..=n` in declaration order.
+///
+/// Field attributes:
+///
+/// * `#[rdbc(rename = "arg")]` - bind as argument `arg` instead of the field name.
+/// * `#[rdbc(flatten)]` - append the arguments of the field's own `ToArguments`.
+/// * `#[rdbc(skip)]` - do not bind the field.
+///
+/// `#[rdbc(default)]` only affects `FromRow` and is ignored, so one struct can
+/// derive both macros.
+#[proc_macro_derive(ToArguments, attributes(rdbc))]
+pub fn derive_to_arguments(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    to_arguments::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,58 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Result};
+
+use crate::attr::{field_name, named_fields, ContainerAttrs, FieldAttrs};
+
+pub fn expand(input: DeriveInput) -> Result<TokenStream> {
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let container_attrs = ContainerAttrs::parse(&input.attrs)?;
+
+    let mut pushes = vec![];
+
+    for field in named_fields(&input)? {
+        let attrs = FieldAttrs::parse(&field.attrs)?;
+        let field_ident = field.ident.as_ref().unwrap();
+
+        if attrs.skip {
+            continue;
+        }
+
+        if attrs.flatten {
+            pushes.push(quote! {
+                args.extend(::rdbc_rs::driver::ToArguments::to_arguments(&self.#field_ident));
+            });
+        } else {
+            let name = field_name(field, &attrs);
+
+            pushes.push(quote! {
+                args.push(::rdbc_rs::driver::Argument::new(#name, &self.#field_ident));
+            });
+        }
+    }
+
+    let renumber = if container_attrs.positional {
+        quote! {
+            for (index, arg) in args.iter_mut().enumerate() {
+                arg.name = ::rdbc_rs::driver::ArgName::Offset(index + 1);
+            }
+        }
+    } else {
+        quote!()
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::rdbc_rs::driver::ToArguments for #ident #ty_generics #where_clause {
+            fn to_arguments(&self) -> ::std::vec::Vec<::rdbc_rs::driver::Argument> {
+                let mut args = ::std::vec::Vec::new();
+
+                #(#pushes)*
+
+                #renumber
+
+                args
+            }
+        }
+    })
+}
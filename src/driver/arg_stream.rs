@@ -0,0 +1,119 @@
+//! Streaming argument source for large BLOB/TEXT parameters.
+
+use std::{
+    fmt::{Debug, Display},
+    io,
+    sync::{Arc, Mutex},
+};
+
+use futures::{io::AsyncRead, AsyncReadExt};
+
+/// Upper bound of the buffer preallocated by [`ArgStream::read_all`], the
+/// declared length is not trusted beyond it.
+const MAX_PREALLOC: u64 = 64 * 1024;
+
+/// Boxed byte source of [`ArgStream`].
+pub type BoxedReader = Box<dyn AsyncRead + Send + Unpin>;
+
+/// Streaming argument value, see [`super::ArgValue::Stream`].
+///
+/// The reader can be consumed only once: the driver takes it with
+/// [`ArgStream::take_reader`] during [`super::Statement::execute`] and sends the
+/// data incrementally. Clones share the same reader.
+#[derive(Clone)]
+pub struct ArgStream {
+    reader: Arc<Mutex<Option<BoxedReader>>>,
+    len: Option<u64>,
+}
+
+impl ArgStream {
+    /// Create stream argument from `reader`.
+    ///
+    /// # Arguments
+    ///
+    /// * `len` - Total length in bytes if known, some drivers need it up front.
+    pub fn new<R>(reader: R, len: Option<u64>) -> Self
+    where
+        R: AsyncRead + Send + Unpin + 'static,
+    {
+        Self {
+            reader: Arc::new(Mutex::new(Some(Box::new(reader)))),
+            len,
+        }
+    }
+
+    /// Total length in bytes, [`None`] if unknown.
+    pub fn content_length(&self) -> Option<u64> {
+        self.len
+    }
+
+    /// Take the reader, returns [`None`] if it was already taken.
+    pub fn take_reader(&self) -> Option<BoxedReader> {
+        self.reader.lock().unwrap().take()
+    }
+
+    /// Take the reader and read it into memory, for drivers that can't stream
+    /// parameters.
+    pub async fn read_all(&self) -> io::Result<Vec<u8>> {
+        let mut reader = self.take_reader().ok_or_else(|| {
+            io::Error::new(io::ErrorKind::UnexpectedEof, "stream argument consumed")
+        })?;
+
+        let mut buf = Vec::with_capacity(self.len.unwrap_or(0).min(MAX_PREALLOC) as usize);
+
+        reader.read_to_end(&mut buf).await?;
+
+        Ok(buf)
+    }
+}
+
+impl Debug for ArgStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ArgStream").field("len", &self.len).finish()
+    }
+}
+
+impl Display for ArgStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.len {
+            Some(len) => write!(f, "<stream {} bytes>", len),
+            None => write!(f, "<stream>"),
+        }
+    }
+}
+
+/// Two streams are equal if they share the same reader.
+impl PartialEq for ArgStream {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.reader, &other.reader)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::io::Cursor;
+
+    use super::ArgStream;
+    use crate::driver::ArgValue;
+
+    #[async_std::test]
+    async fn test_arg_stream() {
+        let stream = ArgStream::new(Cursor::new(vec![1u8, 2, 3]), Some(3));
+        let value = ArgValue::Stream(stream.clone());
+
+        assert_eq!(value.to_string(), "<stream 3 bytes>");
+        assert_eq!(value, ArgValue::Stream(stream.clone()));
+        assert_ne!(
+            value,
+            ArgValue::Stream(ArgStream::new(Cursor::new(vec![]), None))
+        );
+
+        assert_eq!(stream.read_all().await.unwrap(), vec![1, 2, 3]);
+        assert!(stream.take_reader().is_none());
+        assert!(stream.read_all().await.is_err());
+
+        let stream = ArgStream::new(Cursor::new(vec![4u8, 5]), Some(u64::MAX));
+
+        assert_eq!(stream.read_all().await.unwrap(), vec![4, 5]);
+    }
+}
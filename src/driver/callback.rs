@@ -19,7 +19,7 @@ pub struct CallbackVTable<Output> {
 impl<Output> CallbackVTable<Output> {
     fn new<F>() -> Self
     where
-        F: FnOnce(Result<Output>),
+        F: FnOnce(Result<Output>) + 'static,
     {
         CallbackVTable {
             drop: drop::<F, Output>,
@@ -55,14 +55,30 @@ where
 }
 
 /// Type erased Callback wrapper struct
+///
+/// The callback is [`Send`], so drivers may invoke it from another thread,
+/// e.g. a connection opened on a background task. The wrapped function must
+/// not borrow from the caller's stack:
+///
+/// ```compile_fail
+/// use rdbc_rs::driver::callback::BoxedCallback;
+///
+/// let name = String::from("abc");
+///
+/// let callback: BoxedCallback<usize> = BoxedCallback::new(|_| println!("{}", &name));
+/// ```
 pub struct BoxedCallback<Output> {
     vtable: NonNull<CallbackVTable<Output>>,
 }
 
+/// The wrapped function is required to be [`Send`] by [`BoxedCallback::new`],
+/// and the output is moved into it.
+unsafe impl<Output: Send> Send for BoxedCallback<Output> {}
+
 impl<Output> BoxedCallback<Output> {
     pub fn new<F>(f: F) -> Self
     where
-        F: FnOnce(Result<Output>),
+        F: FnOnce(Result<Output>) + Send + 'static,
     {
         let boxed = Box::new(Callback::<F, Output> {
             vtable: CallbackVTable::<Output>::new::<F>(),
@@ -97,4 +113,15 @@ mod tests {
 
         boxed.invoke(Ok(1));
     }
+
+    #[test]
+    fn test_send() {
+        let boxed: BoxedCallback<usize> = BoxedCallback::new(|v| {
+            assert_eq!(v.unwrap(), 2);
+        });
+
+        std::thread::spawn(move || boxed.invoke(Ok(2)))
+            .join()
+            .unwrap();
+    }
 }
@@ -1,4 +1,4 @@
-use super::{callback::BoxedCallback, Statement, Transaction};
+use super::{callback::BoxedCallback, RDBCError, Statement, Transaction};
 
 pub trait Connection: Send {
     /// Returns a prepared statement, bound to this connection.
@@ -11,6 +11,18 @@ pub trait Connection: Send {
 
     /// Get connection unique id
     fn id(&self) -> &str;
+
+    /// Check the connection is still usable, e.g. by a round trip to the server.
+    ///
+    /// The default implementation only checks [`Connection::conn_status`].
+    fn ping(&mut self, callback: BoxedCallback<()>) {
+        match self.conn_status() {
+            ConnStatus::Connected => callback.invoke(Ok(())),
+            ConnStatus::Disconnected => {
+                callback.invoke(Err(RDBCError::Closed("connection".to_owned()).into()))
+            }
+        }
+    }
 }
 
 pub enum ConnStatus {
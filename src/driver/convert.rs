@@ -0,0 +1,539 @@
+//! Conversions between Rust types and [`ArgValue`].
+
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+
+use super::{ArgStream, ArgValue, Argument, ColumnType, Decimal, RDBCError};
+
+/// Convert a Rust value into statement argument value.
+pub trait ToArgValue {
+    fn to_arg_value(&self) -> ArgValue;
+}
+
+/// Convert a column value returned by [`super::Rows::get`] into Rust value.
+pub trait FromArgValue: Sized {
+    /// The column type requested from driver when reading this type.
+    fn column_type() -> ColumnType;
+
+    /// Convert column value, [`None`] and [`ArgValue::Null`] both mean SQL `NULL`.
+    fn from_arg_value(value: Option<ArgValue>) -> Result<Self, RDBCError>;
+}
+
+/// Convert a Rust value into statement argument list.
+///
+/// Available as `#[derive(ToArguments)]` on feature *derive*.
+pub trait ToArguments {
+    fn to_arguments(&self) -> Vec<Argument>;
+}
+
+/// Used by [`crate::params!`] and [`crate::named_params!`], widens `value` to
+/// [`ArgValue::I64`] if `source` is an unsuffixed integer literal, which Rust
+/// types as `i32`.
+#[doc(hidden)]
+pub fn literal_arg_value(source: &str, value: ArgValue) -> ArgValue {
+    match value {
+        ArgValue::I32(value) if is_unsuffixed_integer(source) => ArgValue::I64(value as i64),
+        value => value,
+    }
+}
+
+fn is_unsuffixed_integer(source: &str) -> bool {
+    let source = source
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '_')
+        .collect::<String>();
+
+    let source = source.strip_prefix('-').unwrap_or(&source);
+
+    let (digits, radix) = match source.get(..2) {
+        Some("0x") => (&source[2..], 16),
+        Some("0o") => (&source[2..], 8),
+        Some("0b") => (&source[2..], 2),
+        _ => (source, 10),
+    };
+
+    !digits.is_empty() && digits.chars().all(|c| c.is_digit(radix))
+}
+
+fn not_null<T>(value: Option<ArgValue>) -> Result<ArgValue, RDBCError> {
+    match value {
+        None | Some(ArgValue::Null) => Err(RDBCError::UnexpectNull(
+            std::any::type_name::<T>().to_owned(),
+        )),
+        Some(value) => Ok(value),
+    }
+}
+
+fn convert_error<T>(value: &ArgValue) -> RDBCError {
+    RDBCError::ConvertError(value.to_string(), std::any::type_name::<T>().to_owned())
+}
+
+impl<T> ToArgValue for &T
+where
+    T: ToArgValue + ?Sized,
+{
+    fn to_arg_value(&self) -> ArgValue {
+        (*self).to_arg_value()
+    }
+}
+
+impl<T> ToArgValue for Option<T>
+where
+    T: ToArgValue,
+{
+    fn to_arg_value(&self) -> ArgValue {
+        match self {
+            Some(v) => v.to_arg_value(),
+            None => ArgValue::Null,
+        }
+    }
+}
+
+impl<T> FromArgValue for Option<T>
+where
+    T: FromArgValue,
+{
+    fn column_type() -> ColumnType {
+        T::column_type()
+    }
+
+    fn from_arg_value(value: Option<ArgValue>) -> Result<Self, RDBCError> {
+        match value {
+            None | Some(ArgValue::Null) => Ok(None),
+            value => T::from_arg_value(value).map(Some),
+        }
+    }
+}
+
+impl ToArgValue for ArgValue {
+    fn to_arg_value(&self) -> ArgValue {
+        self.clone()
+    }
+}
+
+macro_rules! numeric_convert {
+    ($($t: ty => $variant: ident),*) => {
+        $(
+            impl ToArgValue for $t {
+                fn to_arg_value(&self) -> ArgValue {
+                    ArgValue::$variant(*self)
+                }
+            }
+
+            impl FromArgValue for $t {
+                fn column_type() -> ColumnType {
+                    ColumnType::$variant
+                }
+
+                fn from_arg_value(value: Option<ArgValue>) -> Result<Self, RDBCError> {
+                    let value = not_null::<Self>(value)?;
+
+                    if let ArgValue::String(s) = &value {
+                        return s.trim().parse().map_err(|_| convert_error::<Self>(&value));
+                    }
+
+                    match value.cast(&ColumnType::$variant)? {
+                        ArgValue::$variant(v) => Ok(v),
+                        value => Err(convert_error::<Self>(&value)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+numeric_convert!(
+    bool => Bool,
+    i8 => I8,
+    i16 => I16,
+    i32 => I32,
+    i64 => I64,
+    u8 => U8,
+    u16 => U16,
+    u32 => U32,
+    u64 => U64,
+    f32 => F32,
+    f64 => F64
+);
+
+impl ToArgValue for str {
+    fn to_arg_value(&self) -> ArgValue {
+        ArgValue::String(self.to_owned())
+    }
+}
+
+impl ToArgValue for String {
+    fn to_arg_value(&self) -> ArgValue {
+        ArgValue::String(self.clone())
+    }
+}
+
+impl FromArgValue for String {
+    fn column_type() -> ColumnType {
+        ColumnType::String
+    }
+
+    fn from_arg_value(value: Option<ArgValue>) -> Result<Self, RDBCError> {
+        match not_null::<Self>(value)? {
+            ArgValue::String(v) => Ok(v),
+            ArgValue::Bytes(v) => String::from_utf8(v)
+                .map_err(|err| convert_error::<Self>(&ArgValue::Bytes(err.into_bytes()))),
+            value => Err(convert_error::<Self>(&value)),
+        }
+    }
+}
+
+impl ToArgValue for [u8] {
+    fn to_arg_value(&self) -> ArgValue {
+        ArgValue::Bytes(self.to_vec())
+    }
+}
+
+impl ToArgValue for Vec<u8> {
+    fn to_arg_value(&self) -> ArgValue {
+        ArgValue::Bytes(self.clone())
+    }
+}
+
+impl FromArgValue for Vec<u8> {
+    fn column_type() -> ColumnType {
+        ColumnType::Bytes
+    }
+
+    fn from_arg_value(value: Option<ArgValue>) -> Result<Self, RDBCError> {
+        match not_null::<Self>(value)? {
+            ArgValue::Bytes(v) => Ok(v),
+            ArgValue::String(v) => Ok(v.into_bytes()),
+            value => Err(convert_error::<Self>(&value)),
+        }
+    }
+}
+
+impl ToArgValue for Decimal {
+    fn to_arg_value(&self) -> ArgValue {
+        ArgValue::Decimal(self.clone())
+    }
+}
+
+impl FromArgValue for Decimal {
+    fn column_type() -> ColumnType {
+        ColumnType::Decimal {
+            precision: None,
+            scale: None,
+        }
+    }
+
+    fn from_arg_value(value: Option<ArgValue>) -> Result<Self, RDBCError> {
+        match not_null::<Self>(value)? {
+            ArgValue::Decimal(v) => Ok(v),
+            ArgValue::String(v) => v.trim().parse(),
+            ArgValue::I8(v) => Ok(v.into()),
+            ArgValue::I16(v) => Ok(v.into()),
+            ArgValue::I32(v) => Ok(v.into()),
+            ArgValue::I64(v) => Ok(v.into()),
+            ArgValue::U8(v) => Ok(v.into()),
+            ArgValue::U16(v) => Ok(v.into()),
+            ArgValue::U32(v) => Ok(v.into()),
+            ArgValue::U64(v) => Ok(v.into()),
+            ArgValue::F32(v) => Decimal::try_from(v as f64),
+            ArgValue::F64(v) => Decimal::try_from(v),
+            value => Err(convert_error::<Self>(&value)),
+        }
+    }
+}
+
+impl ToArgValue for ArgStream {
+    fn to_arg_value(&self) -> ArgValue {
+        ArgValue::Stream(self.clone())
+    }
+}
+
+macro_rules! temporal_convert {
+    ($($t: ty => $variant: ident),*) => {
+        $(
+            impl ToArgValue for $t {
+                fn to_arg_value(&self) -> ArgValue {
+                    ArgValue::$variant(self.clone())
+                }
+            }
+        )*
+    };
+}
+
+temporal_convert!(
+    NaiveDate => Date,
+    NaiveTime => Time,
+    NaiveDateTime => DateTime,
+    DateTime<Utc> => DateTimeUtc,
+    DateTime<FixedOffset> => DateTimeTz
+);
+
+impl FromArgValue for NaiveDate {
+    fn column_type() -> ColumnType {
+        ColumnType::Date
+    }
+
+    fn from_arg_value(value: Option<ArgValue>) -> Result<Self, RDBCError> {
+        match not_null::<Self>(value)? {
+            ArgValue::Date(v) => Ok(v),
+            ArgValue::DateTime(v) => Ok(v.date()),
+            ArgValue::String(v) => v.parse().map_err(|_| convert_error::<Self>(&v.into())),
+            value => Err(convert_error::<Self>(&value)),
+        }
+    }
+}
+
+impl FromArgValue for NaiveTime {
+    fn column_type() -> ColumnType {
+        ColumnType::Time
+    }
+
+    fn from_arg_value(value: Option<ArgValue>) -> Result<Self, RDBCError> {
+        match not_null::<Self>(value)? {
+            ArgValue::Time(v) => Ok(v),
+            ArgValue::String(v) => v.parse().map_err(|_| convert_error::<Self>(&v.into())),
+            value => Err(convert_error::<Self>(&value)),
+        }
+    }
+}
+
+impl FromArgValue for NaiveDateTime {
+    fn column_type() -> ColumnType {
+        ColumnType::DateTime
+    }
+
+    fn from_arg_value(value: Option<ArgValue>) -> Result<Self, RDBCError> {
+        match not_null::<Self>(value)? {
+            ArgValue::DateTime(v) => Ok(v),
+            ArgValue::String(v) => NaiveDateTime::parse_from_str(&v, "%Y-%m-%d %H:%M:%S%.f")
+                .or_else(|_| v.parse())
+                .map_err(|_| convert_error::<Self>(&v.into())),
+            value => Err(convert_error::<Self>(&value)),
+        }
+    }
+}
+
+impl FromArgValue for DateTime<Utc> {
+    fn column_type() -> ColumnType {
+        ColumnType::DateTimeUtc
+    }
+
+    fn from_arg_value(value: Option<ArgValue>) -> Result<Self, RDBCError> {
+        match not_null::<Self>(value)? {
+            ArgValue::DateTimeUtc(v) => Ok(v),
+            ArgValue::DateTimeTz(v) => Ok(v.with_timezone(&Utc)),
+            ArgValue::String(v) => DateTime::parse_from_rfc3339(&v)
+                .map(|v| v.with_timezone(&Utc))
+                .map_err(|_| convert_error::<Self>(&v.into())),
+            value => Err(convert_error::<Self>(&value)),
+        }
+    }
+}
+
+impl FromArgValue for DateTime<FixedOffset> {
+    fn column_type() -> ColumnType {
+        ColumnType::DateTimeTz
+    }
+
+    fn from_arg_value(value: Option<ArgValue>) -> Result<Self, RDBCError> {
+        match not_null::<Self>(value)? {
+            ArgValue::DateTimeTz(v) => Ok(v),
+            ArgValue::DateTimeUtc(v) => Ok(v.into()),
+            ArgValue::String(v) => {
+                DateTime::parse_from_rfc3339(&v).map_err(|_| convert_error::<Self>(&v.into()))
+            }
+            value => Err(convert_error::<Self>(&value)),
+        }
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl ToArgValue for uuid::Uuid {
+    fn to_arg_value(&self) -> ArgValue {
+        ArgValue::Uuid(*self)
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl FromArgValue for uuid::Uuid {
+    fn column_type() -> ColumnType {
+        ColumnType::Uuid
+    }
+
+    fn from_arg_value(value: Option<ArgValue>) -> Result<Self, RDBCError> {
+        match not_null::<Self>(value)? {
+            ArgValue::Uuid(v) => Ok(v),
+            ArgValue::String(v) => v.parse().map_err(|_| convert_error::<Self>(&v.into())),
+            ArgValue::Bytes(v) => {
+                uuid::Uuid::from_slice(&v).map_err(|_| convert_error::<Self>(&v.into()))
+            }
+            value => Err(convert_error::<Self>(&value)),
+        }
+    }
+}
+
+#[cfg(feature = "json")]
+impl ToArgValue for serde_json::Value {
+    fn to_arg_value(&self) -> ArgValue {
+        ArgValue::Json(self.clone())
+    }
+}
+
+#[cfg(feature = "json")]
+impl FromArgValue for serde_json::Value {
+    fn column_type() -> ColumnType {
+        ColumnType::Json
+    }
+
+    fn from_arg_value(value: Option<ArgValue>) -> Result<Self, RDBCError> {
+        match not_null::<Self>(value)? {
+            ArgValue::Json(v) => Ok(v),
+            ArgValue::String(v) => {
+                serde_json::from_str(&v).map_err(|_| convert_error::<Self>(&v.into()))
+            }
+            value => Err(convert_error::<Self>(&value)),
+        }
+    }
+}
+
+impl From<String> for ArgValue {
+    fn from(value: String) -> Self {
+        ArgValue::String(value)
+    }
+}
+
+impl From<Vec<u8>> for ArgValue {
+    fn from(value: Vec<u8>) -> Self {
+        ArgValue::Bytes(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, NaiveDateTime};
+
+    use rdbc_derive::ToArguments;
+
+    use super::{FromArgValue, ToArgValue, ToArguments};
+    use crate::driver::{ArgName, ArgValue, Argument, ColumnType, Decimal, RDBCError};
+
+    #[derive(ToArguments)]
+    struct Audit {
+        created_by: String,
+    }
+
+    #[derive(ToArguments)]
+    struct Order {
+        id: u64,
+        #[rdbc(rename = "order_note")]
+        note: Option<String>,
+        #[rdbc(skip)]
+        #[allow(dead_code)]
+        cached: Vec<u8>,
+        #[rdbc(flatten)]
+        audit: Audit,
+    }
+
+    #[derive(ToArguments)]
+    #[rdbc(positional)]
+    struct Point {
+        x: f64,
+        y: f64,
+    }
+
+    #[test]
+    fn test_to_arg_value() {
+        assert_eq!(5i64.to_arg_value(), ArgValue::I64(5));
+        assert_eq!(true.to_arg_value(), ArgValue::Bool(true));
+        assert_eq!("abc".to_arg_value(), ArgValue::String("abc".to_owned()));
+        assert_eq!(vec![1u8, 2].to_arg_value(), ArgValue::Bytes(vec![1, 2]));
+        assert_eq!(None::<i64>.to_arg_value(), ArgValue::Null);
+        assert_eq!(Some(1.5f32).to_arg_value(), ArgValue::F32(1.5));
+    }
+
+    #[test]
+    fn test_from_arg_value() {
+        assert_eq!(i32::from_arg_value(Some(ArgValue::I64(5))).unwrap(), 5);
+        assert_eq!(
+            u64::from_arg_value(Some(ArgValue::String("18446744073709551615".to_owned()))).unwrap(),
+            u64::MAX
+        );
+        assert!(!bool::from_arg_value(Some(ArgValue::I64(0))).unwrap());
+        assert_eq!(
+            Option::<i64>::from_arg_value(Some(ArgValue::Null)).unwrap(),
+            None
+        );
+        assert_eq!(Option::<i64>::from_arg_value(None).unwrap(), None);
+        assert_eq!(
+            Option::<i64>::column_type(),
+            <i64 as FromArgValue>::column_type()
+        );
+        assert_eq!(
+            Decimal::from_arg_value(Some(ArgValue::String("1.10".to_owned()))).unwrap(),
+            Decimal::new(110, 2)
+        );
+        assert_eq!(
+            Decimal::from_arg_value(Some(ArgValue::I64(3))).unwrap(),
+            Decimal::from(3)
+        );
+        assert_eq!(
+            NaiveDateTime::from_arg_value(Some(ArgValue::String("2022-11-03 08:30:05".to_owned())))
+                .unwrap(),
+            NaiveDate::from_ymd_opt(2022, 11, 3)
+                .unwrap()
+                .and_hms_opt(8, 30, 5)
+                .unwrap()
+        );
+        assert_eq!(String::column_type(), ColumnType::String);
+    }
+
+    #[test]
+    fn test_from_arg_value_error() {
+        assert!(matches!(
+            i64::from_arg_value(None),
+            Err(RDBCError::UnexpectNull(_))
+        ));
+        assert!(matches!(
+            i8::from_arg_value(Some(ArgValue::I64(1000))),
+            Err(RDBCError::Overflow(_, _))
+        ));
+        assert!(matches!(
+            String::from_arg_value(Some(ArgValue::I64(1))),
+            Err(RDBCError::ConvertError(_, _))
+        ));
+    }
+
+    #[test]
+    fn test_derive_to_arguments() {
+        let order = Order {
+            id: 7,
+            note: None,
+            cached: vec![1],
+            audit: Audit {
+                created_by: "admin".to_owned(),
+            },
+        };
+
+        assert_eq!(
+            order.to_arguments(),
+            vec![
+                Argument::new("id", 7u64),
+                Argument::new("order_note", ArgValue::Null),
+                Argument::new("created_by", "admin"),
+            ]
+        );
+
+        assert_eq!(
+            Point { x: 1.0, y: 2.0 }.to_arguments(),
+            vec![
+                Argument {
+                    name: ArgName::Offset(1),
+                    value: ArgValue::F64(1.0)
+                },
+                Argument {
+                    name: ArgName::Offset(2),
+                    value: ArgValue::F64(2.0)
+                },
+            ]
+        );
+    }
+}
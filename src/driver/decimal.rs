@@ -0,0 +1,203 @@
+//! Exact decimal number type used by SQL `NUMERIC`/`DECIMAL` columns.
+
+use std::{fmt::Display, str::FromStr};
+
+use super::RDBCError;
+
+/// Arbitrary precision decimal number.
+///
+/// The value is kept as a sequence of decimal digits plus a scale, so binding
+/// or reading a `NUMERIC` value through [`super::ArgValue::Decimal`] never goes
+/// through a binary floating point representation. The scale is preserved as
+/// given, which means `1.5` and `1.50` are different values for [`PartialEq`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Decimal {
+    negative: bool,
+    /// Unscaled digits without sign and leading zeros, `"0"` for zero.
+    digits: String,
+    scale: u32,
+}
+
+impl Decimal {
+    /// Create decimal `unscaled * 10^-scale`.
+    pub fn new(unscaled: i128, scale: u32) -> Self {
+        Self {
+            negative: unscaled < 0,
+            digits: unscaled.unsigned_abs().to_string(),
+            scale,
+        }
+    }
+
+    /// Returns true if the number is less than zero.
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Number of digits after the decimal point.
+    pub fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    /// Total number of significant digits, as in SQL `NUMERIC(precision, scale)`.
+    pub fn precision(&self) -> u32 {
+        (self.digits.len() as u32).max(self.scale)
+    }
+
+    /// Returns the unscaled value if it fits into [`i128`].
+    pub fn unscaled(&self) -> Option<i128> {
+        // Parse with the sign attached, `i128::MIN` has no positive counterpart.
+        if self.negative {
+            format!("-{}", self.digits).parse().ok()
+        } else {
+            self.digits.parse().ok()
+        }
+    }
+
+    /// Convert to the nearest [`f64`], this conversion may lose precision.
+    pub fn to_f64(&self) -> f64 {
+        self.to_string().parse().unwrap_or(f64::NAN)
+    }
+}
+
+impl Display for Decimal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.negative {
+            write!(f, "-")?;
+        }
+
+        let scale = self.scale as usize;
+
+        if scale == 0 {
+            return write!(f, "{}", self.digits);
+        }
+
+        if self.digits.len() > scale {
+            let (int, frac) = self.digits.split_at(self.digits.len() - scale);
+            write!(f, "{}.{}", int, frac)
+        } else {
+            write!(f, "0.{:0>width$}", self.digits, width = scale)
+        }
+    }
+}
+
+impl FromStr for Decimal {
+    type Err = RDBCError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || RDBCError::InvalidDecimal(s.to_owned());
+
+        let (negative, unsigned) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+
+        let (int, frac) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+
+        if int.is_empty() && frac.is_empty() {
+            return Err(invalid());
+        }
+
+        if !int.bytes().chain(frac.bytes()).all(|c| c.is_ascii_digit()) {
+            return Err(invalid());
+        }
+
+        let digits = format!("{}{}", int, frac);
+        let digits = digits.trim_start_matches('0');
+
+        let digits = if digits.is_empty() { "0" } else { digits };
+
+        Ok(Self {
+            negative: negative && digits != "0",
+            digits: digits.to_owned(),
+            scale: frac.len() as u32,
+        })
+    }
+}
+
+macro_rules! decimal_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Decimal {
+                fn from(value: $t) -> Self {
+                    Self::new(value as i128, 0)
+                }
+            }
+        )*
+    };
+}
+
+decimal_from_int!(i8, i16, i32, i64, i128, u8, u16, u32, u64);
+
+impl TryFrom<f64> for Decimal {
+    type Error = RDBCError;
+
+    /// Convert from the shortest decimal representation of `value`.
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        if !value.is_finite() {
+            return Err(RDBCError::InvalidDecimal(value.to_string()));
+        }
+
+        value.to_string().parse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Decimal;
+
+    #[test]
+    fn test_parse_display() {
+        for s in [
+            "0",
+            "1.50",
+            "-0.005",
+            "12345678901234567890123456789012345678901.1",
+        ] {
+            let decimal: Decimal = s.parse().unwrap();
+            assert_eq!(decimal.to_string(), s);
+        }
+
+        assert_eq!("-0.00".parse::<Decimal>().unwrap().to_string(), "0.00");
+        assert_eq!("007.10".parse::<Decimal>().unwrap().to_string(), "7.10");
+        assert_eq!(".5".parse::<Decimal>().unwrap().to_string(), "0.5");
+
+        assert!("".parse::<Decimal>().is_err());
+        assert!("1.2.3".parse::<Decimal>().is_err());
+        assert!("1e5".parse::<Decimal>().is_err());
+    }
+
+    #[test]
+    fn test_precision_scale() {
+        let decimal: Decimal = "-123.4500".parse().unwrap();
+
+        assert!(decimal.is_negative());
+        assert_eq!(decimal.precision(), 7);
+        assert_eq!(decimal.scale(), 4);
+        assert_eq!(decimal.unscaled(), Some(-1234500));
+        assert_eq!(decimal, Decimal::new(-1234500, 4));
+
+        assert_eq!("0.05".parse::<Decimal>().unwrap().precision(), 2);
+    }
+
+    #[test]
+    fn test_unscaled_bounds() {
+        for value in [i128::MIN, i128::MAX] {
+            let decimal = Decimal::new(value, 3);
+
+            assert_eq!(decimal.unscaled(), Some(value));
+            assert_eq!(decimal.to_string().parse::<Decimal>().unwrap(), decimal);
+        }
+
+        let overflow: Decimal = "-170141183460469231731687303715884105729".parse().unwrap();
+        assert_eq!(overflow.unscaled(), None);
+    }
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(Decimal::from(u64::MAX).to_string(), u64::MAX.to_string());
+        assert_eq!(Decimal::try_from(0.1f64).unwrap().to_string(), "0.1");
+        assert!(Decimal::try_from(f64::NAN).is_err());
+        assert_eq!("2.25".parse::<Decimal>().unwrap().to_f64(), 2.25);
+    }
+}
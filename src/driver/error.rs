@@ -21,4 +21,25 @@ pub enum RDBCError {
 
     #[error("Get column by name {0}, not found")]
     UnknownColumn(String),
+
+    #[error("Invalid decimal literal '{0}'")]
+    InvalidDecimal(String),
+
+    #[error("Value {0} overflows column type {1}")]
+    Overflow(String, String),
+
+    #[error("Unexpect NULL value converting to {0}")]
+    UnexpectNull(String),
+
+    #[error("Convert value {0} to {1} failed")]
+    ConvertError(String, String),
+
+    #[error("Driver does not support {0}")]
+    Unsupported(String),
+
+    #[error("Use of closed {0}")]
+    Closed(String),
+
+    #[error("Timeout {0}")]
+    Timeout(String),
 }
@@ -0,0 +1,331 @@
+//! In memory driver for unit tests.
+
+use std::sync::{
+    atomic::{AtomicBool, AtomicUsize, Ordering},
+    Arc, Mutex,
+};
+
+use anyhow::Result;
+
+use crate::{DataSource, Database};
+
+use super::{
+    callback::BoxedCallback, ArgName, ArgValue, Argument, Column, ColumnType, ConnStatus,
+    Connection, Driver, ExecResult, QueryOptions, RDBCError, Rows, Statement, Transaction,
+};
+
+/// Result set returned by every query.
+pub(crate) struct MockTable {
+    pub columns: Vec<Column>,
+    pub rows: Vec<Vec<ArgValue>>,
+    /// Implement [`Rows::next_row`].
+    pub next_row: bool,
+    /// Implement [`Rows::read_chunk`].
+    pub read_chunk: bool,
+    /// Result sets returned after this one by [`Rows::next_result_set`].
+    pub result_sets: Vec<MockTable>,
+    /// Number of [`Rows::close`] calls.
+    pub rows_closed: Arc<AtomicUsize>,
+    /// Number of [`Statement::close`] calls.
+    pub stmts_closed: Arc<AtomicUsize>,
+    /// Make connections report [`ConnStatus::Disconnected`].
+    pub disconnected: Arc<AtomicBool>,
+    /// Make [`Driver::open_async`] fail.
+    pub fail_open: Arc<AtomicBool>,
+    /// Make [`Connection::prepare`] and [`Connection::begin`] never complete.
+    pub pending: Arc<AtomicBool>,
+    /// Options of the last [`Statement::query_with`] call.
+    pub query_options: Arc<Mutex<Option<QueryOptions>>>,
+}
+
+impl MockTable {
+    pub fn new(columns: &[&str], rows: Vec<Vec<ArgValue>>) -> Self {
+        Self {
+            columns: columns
+                .iter()
+                .enumerate()
+                .map(|(i, name)| Column {
+                    column_index: i as u64,
+                    column_name: name.to_string(),
+                    ..Default::default()
+                })
+                .collect(),
+            rows,
+            next_row: false,
+            read_chunk: false,
+            result_sets: vec![],
+            rows_closed: Default::default(),
+            stmts_closed: Default::default(),
+            disconnected: Default::default(),
+            fail_open: Default::default(),
+            pending: Default::default(),
+            query_options: Default::default(),
+        }
+    }
+}
+
+/// Open [`Database`] on a new [`MockDriver`] serving `table`.
+pub(crate) fn open(table: MockTable) -> Database {
+    let datasource = DataSource::default();
+
+    datasource.register("mock", MockDriver::new(table)).unwrap();
+
+    datasource.open("mock", "").unwrap()
+}
+
+pub(crate) struct MockDriver {
+    table: Arc<MockTable>,
+    next_id: Arc<AtomicUsize>,
+}
+
+impl MockDriver {
+    pub fn new(table: MockTable) -> Self {
+        Self {
+            table: Arc::new(table),
+            next_id: Default::default(),
+        }
+    }
+}
+
+impl Driver for MockDriver {
+    fn open(&mut self, _url: &str) -> Result<Box<dyn Connection>> {
+        unreachable!("connection pools open connections with open_async")
+    }
+
+    fn open_async(&mut self, _url: &str, callback: BoxedCallback<Box<dyn Connection>>) {
+        if self.table.fail_open.load(Ordering::SeqCst) {
+            return callback.invoke(Err(
+                RDBCError::NativeError(1, "open failed".to_owned()).into()
+            ));
+        }
+
+        callback.invoke(Ok(Box::new(MockConnection {
+            table: self.table.clone(),
+            id: self.next_id.fetch_add(1, Ordering::SeqCst).to_string(),
+        })));
+    }
+}
+
+struct MockConnection {
+    table: Arc<MockTable>,
+    id: String,
+}
+
+impl Connection for MockConnection {
+    fn prepare(&mut self, _query: String, callback: BoxedCallback<Box<dyn Statement>>) {
+        if self.table.pending.load(Ordering::SeqCst) {
+            return;
+        }
+
+        callback.invoke(Ok(Box::new(MockStatement {
+            table: self.table.clone(),
+        })));
+    }
+
+    fn begin(&mut self, callback: BoxedCallback<Box<dyn Transaction>>) {
+        if self.table.pending.load(Ordering::SeqCst) {
+            return;
+        }
+
+        callback.invoke(Ok(Box::new(MockTransaction {
+            table: self.table.clone(),
+        })));
+    }
+
+    fn conn_status(&self) -> ConnStatus {
+        if self.table.disconnected.load(Ordering::SeqCst) {
+            ConnStatus::Disconnected
+        } else {
+            ConnStatus::Connected
+        }
+    }
+
+    fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+struct MockTransaction {
+    table: Arc<MockTable>,
+}
+
+impl Transaction for MockTransaction {
+    fn prepare(&mut self, _query: String, callback: BoxedCallback<Box<dyn Statement>>) {
+        callback.invoke(Ok(Box::new(MockStatement {
+            table: self.table.clone(),
+        })));
+    }
+
+    fn commit(&mut self, callback: BoxedCallback<()>) {
+        callback.invoke(Ok(()));
+    }
+
+    fn rollback(&mut self, callback: BoxedCallback<()>) {
+        callback.invoke(Ok(()));
+    }
+}
+
+struct MockStatement {
+    table: Arc<MockTable>,
+}
+
+impl Statement for MockStatement {
+    fn num_input(&self, callback: BoxedCallback<Option<usize>>) {
+        callback.invoke(Ok(None));
+    }
+
+    fn execute(&mut self, args: Vec<Argument>, callback: BoxedCallback<ExecResult>) {
+        callback.invoke(Ok(ExecResult {
+            last_insert_id: 0,
+            raws_affected: args.len() as u64,
+        }));
+    }
+
+    fn query(&mut self, _args: Vec<Argument>, callback: BoxedCallback<Box<dyn Rows>>) {
+        callback.invoke(Ok(Box::new(MockRows {
+            table: self.table.clone(),
+            result_set: 0,
+            cursor: None,
+        })));
+    }
+
+    fn query_with(
+        &mut self,
+        args: Vec<Argument>,
+        options: QueryOptions,
+        callback: BoxedCallback<Box<dyn Rows>>,
+    ) {
+        *self.table.query_options.lock().unwrap() = Some(options);
+
+        self.query(args, callback)
+    }
+
+    fn close(&mut self, callback: BoxedCallback<()>) {
+        self.table.stmts_closed.fetch_add(1, Ordering::SeqCst);
+
+        callback.invoke(Ok(()));
+    }
+}
+
+struct MockRows {
+    table: Arc<MockTable>,
+    result_set: usize,
+    cursor: Option<usize>,
+}
+
+impl MockRows {
+    fn current(&self) -> &MockTable {
+        match self.result_set {
+            0 => &self.table,
+            n => &self.table.result_sets[n - 1],
+        }
+    }
+}
+
+impl Rows for MockRows {
+    fn colunms(&mut self, callback: BoxedCallback<Vec<Column>>) {
+        callback.invoke(Ok(self.current().columns.clone()));
+    }
+
+    fn next(&mut self, callback: BoxedCallback<bool>) {
+        let cursor = self.cursor.map(|c| c + 1).unwrap_or(0);
+
+        self.cursor = Some(cursor);
+
+        callback.invoke(Ok(cursor < self.current().rows.len()));
+    }
+
+    fn get(
+        &mut self,
+        name: ArgName,
+        column_type: ColumnType,
+        callback: BoxedCallback<Option<ArgValue>>,
+    ) {
+        let table = self.current();
+
+        let row = match self.cursor.and_then(|c| table.rows.get(c)) {
+            Some(row) => row,
+            None => return callback.invoke(Err(RDBCError::NextDataError.into())),
+        };
+
+        let index = match &name {
+            ArgName::Offset(index) => table
+                .columns
+                .iter()
+                .position(|c| c.column_index as usize == *index),
+            ArgName::String(name) => table.columns.iter().position(|c| &c.column_name == name),
+        };
+
+        let value = match index.and_then(|i| row.get(i)) {
+            Some(value) => value.clone(),
+            None => {
+                return callback.invoke(Err(RDBCError::UnknownColumn(format!("{:?}", name)).into()))
+            }
+        };
+
+        callback.invoke(value.cast(&column_type).map(Some).map_err(Into::into));
+    }
+
+    fn next_row(&mut self, callback: BoxedCallback<Option<Vec<Option<ArgValue>>>>) {
+        if !self.table.next_row {
+            return callback.invoke(Err(RDBCError::Unsupported("next_row".to_owned()).into()));
+        }
+
+        let cursor = self.cursor.map(|c| c + 1).unwrap_or(0);
+
+        self.cursor = Some(cursor);
+
+        callback.invoke(Ok(self
+            .current()
+            .rows
+            .get(cursor)
+            .map(|row| row.iter().cloned().map(Some).collect())));
+    }
+
+    fn next_result_set(&mut self, callback: BoxedCallback<bool>) {
+        if self.result_set < self.table.result_sets.len() {
+            self.result_set += 1;
+            self.cursor = None;
+
+            callback.invoke(Ok(true));
+        } else {
+            callback.invoke(Ok(false));
+        }
+    }
+
+    fn read_chunk(
+        &mut self,
+        name: ArgName,
+        offset: u64,
+        len: usize,
+        callback: BoxedCallback<Option<Vec<u8>>>,
+    ) {
+        if !self.table.read_chunk {
+            return callback.invoke(Err(RDBCError::Unsupported("read_chunk".to_owned()).into()));
+        }
+
+        self.get(
+            name,
+            ColumnType::Bytes,
+            BoxedCallback::new(move |value: Result<Option<ArgValue>>| {
+                let chunk = value.map(|value| match value {
+                    Some(ArgValue::Bytes(bytes)) => {
+                        let start = (offset as usize).min(bytes.len());
+                        let end = (start + len).min(bytes.len());
+
+                        Some(bytes[start..end].to_vec())
+                    }
+                    _ => None,
+                });
+
+                callback.invoke(chunk);
+            }),
+        );
+    }
+
+    fn close(&mut self, callback: BoxedCallback<()>) {
+        self.table.rows_closed.fetch_add(1, Ordering::SeqCst);
+
+        callback.invoke(Ok(()));
+    }
+}
@@ -1,16 +1,43 @@
 //! # This mode provides a generic interface around SQL database.
 
+pub mod arg_stream;
 pub mod callback;
 pub mod conn;
+pub mod convert;
+pub mod decimal;
 pub mod error;
 pub mod stmt;
 pub mod tx;
+pub mod types;
 
+#[cfg(test)]
+pub(crate) mod mock;
+
+pub use arg_stream::*;
 pub use conn::*;
+pub use convert::*;
+pub use decimal::*;
 pub use error::*;
 pub use stmt::*;
 pub use tx::*;
+pub use types::*;
 
 pub trait Driver: Send {
     fn open(&mut self, url: &str) -> anyhow::Result<Box<dyn Connection>>;
+
+    /// Open connection without blocking the caller, `callback` is invoked
+    /// with the new connection once it is established.
+    ///
+    /// Connection pools call this method, drivers doing network handshakes
+    /// should override it and may move `callback` to the thread or task that
+    /// finishes the handshake. The default implementation calls [`Driver::open`].
+    fn open_async(&mut self, url: &str, callback: callback::BoxedCallback<Box<dyn Connection>>) {
+        callback.invoke(self.open(url))
+    }
+
+    /// Returns the mapping of this driver's column declaration types, used by
+    /// [`crate::Rows::get_value`] when a column doesn't report [`Column::column_type`].
+    fn type_registry(&self) -> TypeRegistry {
+        TypeRegistry::new()
+    }
 }
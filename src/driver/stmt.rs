@@ -1,11 +1,20 @@
 use std::fmt::Display;
 
-use super::callback::BoxedCallback;
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 
-/// SQL argument placeholder name
+use super::{callback::BoxedCallback, ArgStream, Decimal, RDBCError, ToArgValue};
+
+/// SQL argument placeholder name, also used to name result set columns.
 #[derive(Debug, Clone, PartialEq)]
 pub enum ArgName {
+    /// Named placeholder or column name.
     String(String),
+    /// Placeholder position, starting at 1 for [`crate::params!`] and derived
+    /// [`super::ToArguments`].
+    ///
+    /// For result set columns it is [`Column::column_index`] as numbered by
+    /// the driver, which may start at 0 or 1, not the position in the column
+    /// list.
     Offset(usize),
 }
 
@@ -27,53 +36,315 @@ impl From<&str> for ArgName {
     }
 }
 
+/// SQL argument or column value.
+///
+/// Variants depend on cargo features (`uuid`, `json`) and more may be added,
+/// so drivers must handle unknown variants with a wildcard arm.
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub enum ArgValue {
+    Bool(bool),
+    I8(i8),
+    I16(i16),
+    I32(i32),
     I64(i64),
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    F32(f32),
     F64(f64),
     String(String),
     Bytes(Vec<u8>),
+    /// Exact decimal number, e.g. SQL `NUMERIC`/`DECIMAL`.
+    Decimal(Decimal),
+    /// Calendar date without time zone, e.g. SQL `DATE`.
+    Date(NaiveDate),
+    /// Time of day without time zone, e.g. SQL `TIME`.
+    Time(NaiveTime),
+    /// Date and time without time zone, e.g. SQL `TIMESTAMP`/`DATETIME`.
+    DateTime(NaiveDateTime),
+    /// UTC timestamp, e.g. SQL `TIMESTAMP WITH TIME ZONE` normalized to UTC.
+    DateTimeUtc(DateTime<Utc>),
+    /// Timestamp with explicit offset, e.g. SQL `TIMESTAMP WITH TIME ZONE`.
+    DateTimeTz(DateTime<FixedOffset>),
+    /// UUID value, available on feature *uuid*.
+    #[cfg(feature = "uuid")]
+    Uuid(uuid::Uuid),
+    /// JSON document, available on feature *json*.
+    #[cfg(feature = "json")]
+    Json(serde_json::Value),
+    /// Array of values with element type, e.g. Postgres `INT8[]` bound to `ANY($1)`.
+    Array(ColumnType, Vec<ArgValue>),
+    /// Row/composite value, e.g. Postgres `ROW(1, 'a')`.
+    Composite(Vec<ArgValue>),
+    /// BLOB/TEXT parameter read incrementally by the driver during execute.
+    Stream(ArgStream),
     Null,
 }
 
 impl Display for ArgValue {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            Self::Bool(v) => write!(f, "{}", if *v { "TRUE" } else { "FALSE" }),
+            Self::I8(v) => write!(f, "{}", v),
+            Self::I16(v) => write!(f, "{}", v),
+            Self::I32(v) => write!(f, "{}", v),
             Self::I64(v) => write!(f, "{}", v),
+            Self::U8(v) => write!(f, "{}", v),
+            Self::U16(v) => write!(f, "{}", v),
+            Self::U32(v) => write!(f, "{}", v),
+            Self::U64(v) => write!(f, "{}", v),
+            Self::F32(v) => write!(f, "{}", v),
             Self::F64(v) => write!(f, "{}", v),
             Self::String(v) => write!(f, "'{}'", v),
             Self::Bytes(v) => write!(f, "{:x?}", v),
+            Self::Decimal(v) => write!(f, "{}", v),
+            Self::Date(v) => write!(f, "'{}'", v.format("%Y-%m-%d")),
+            Self::Time(v) => write!(f, "'{}'", v.format("%H:%M:%S%.f")),
+            Self::DateTime(v) => write!(f, "'{}'", v.format("%Y-%m-%d %H:%M:%S%.f")),
+            Self::DateTimeUtc(v) => write!(f, "'{}'", v.format("%Y-%m-%d %H:%M:%S%.f%:z")),
+            Self::DateTimeTz(v) => write!(f, "'{}'", v.format("%Y-%m-%d %H:%M:%S%.f%:z")),
+            #[cfg(feature = "uuid")]
+            Self::Uuid(v) => write!(f, "'{}'", v),
+            #[cfg(feature = "json")]
+            Self::Json(v) => write!(f, "'{}'", v),
+            Self::Array(_, v) => write!(f, "ARRAY[{}]", JoinValues(v)),
+            Self::Composite(v) => write!(f, "ROW({})", JoinValues(v)),
+            Self::Stream(v) => write!(f, "{}", v),
             Self::Null => write!(f, "NULL"),
         }
     }
 }
 
+struct JoinValues<'a>(&'a [ArgValue]);
+
+impl<'a> Display for JoinValues<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, v) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "{}", v)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl ArgValue {
+    /// Convert a boolean or numeric value into the width of `column_type`.
+    ///
+    /// Drivers call this to narrow a value to the real wire type of a placeholder
+    /// or column. A value that does not fit the target type, including an
+    /// integer without an exact float representation, is reported as
+    /// [`RDBCError::Overflow`] instead of being truncated. Array elements are
+    /// converted one by one. Non numeric values and non numeric target types
+    /// are returned unchanged.
+    pub fn cast(self, column_type: &ColumnType) -> Result<ArgValue, RDBCError> {
+        let value = match (self, column_type) {
+            (Self::Array(_, values), ColumnType::Array(element_type)) => {
+                let values = values
+                    .into_iter()
+                    .map(|v| v.cast(element_type))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                return Ok(Self::Array(element_type.as_ref().clone(), values));
+            }
+            (value, _) => value,
+        };
+
+        let integer = match &value {
+            Self::Bool(v) => Some(*v as i128),
+            Self::I8(v) => Some(*v as i128),
+            Self::I16(v) => Some(*v as i128),
+            Self::I32(v) => Some(*v as i128),
+            Self::I64(v) => Some(*v as i128),
+            Self::U8(v) => Some(*v as i128),
+            Self::U16(v) => Some(*v as i128),
+            Self::U32(v) => Some(*v as i128),
+            Self::U64(v) => Some(*v as i128),
+            Self::F32(v) if v.fract() == 0.0 => Some(*v as i128),
+            Self::F64(v) if v.fract() == 0.0 => Some(*v as i128),
+            _ => None,
+        };
+
+        let float = match &value {
+            Self::F32(v) => Some(*v as f64),
+            Self::F64(v) => Some(*v),
+            _ => integer.map(|v| v as f64),
+        };
+
+        if float.is_none() {
+            return Ok(value);
+        }
+
+        let overflow = || RDBCError::Overflow(value.to_string(), format!("{:?}", column_type));
+
+        macro_rules! narrow {
+            ($variant: ident, $t: ty) => {
+                integer
+                    .and_then(|v| <$t>::try_from(v).ok())
+                    .map(ArgValue::$variant)
+                    .ok_or_else(overflow)
+            };
+        }
+
+        // Integers must round trip through the float type, e.g. `i64::MAX` has
+        // no exact `f64` representation.
+        let source_integer = match &value {
+            Self::F32(_) | Self::F64(_) => None,
+            _ => integer,
+        };
+
+        match column_type {
+            ColumnType::Bool => match integer {
+                Some(0) => Ok(ArgValue::Bool(false)),
+                Some(1) => Ok(ArgValue::Bool(true)),
+                _ => Err(overflow()),
+            },
+            ColumnType::I8 => narrow!(I8, i8),
+            ColumnType::I16 => narrow!(I16, i16),
+            ColumnType::I32 => narrow!(I32, i32),
+            ColumnType::I64 => narrow!(I64, i64),
+            ColumnType::U8 => narrow!(U8, u8),
+            ColumnType::U16 => narrow!(U16, u16),
+            ColumnType::U32 => narrow!(U32, u32),
+            ColumnType::U64 => narrow!(U64, u64),
+            ColumnType::F32 => match source_integer {
+                Some(v) if (v as f32) as i128 != v => Err(overflow()),
+                Some(v) => Ok(ArgValue::F32(v as f32)),
+                None => {
+                    let v = float.unwrap();
+
+                    if v.is_finite() && v.abs() > f32::MAX as f64 {
+                        Err(overflow())
+                    } else {
+                        Ok(ArgValue::F32(v as f32))
+                    }
+                }
+            },
+            ColumnType::F64 => match source_integer {
+                Some(v) if (v as f64) as i128 != v => Err(overflow()),
+                Some(v) => Ok(ArgValue::F64(v as f64)),
+                None => Ok(ArgValue::F64(float.unwrap())),
+            },
+            _ => Ok(value),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct Argument {
     pub name: ArgName,
     pub value: ArgValue,
 }
 
+impl Argument {
+    /// Create argument from any value implementing [`ToArgValue`].
+    pub fn new<N, V>(name: N, value: V) -> Self
+    where
+        N: Into<ArgName>,
+        V: ToArgValue,
+    {
+        Self {
+            name: name.into(),
+            value: value.to_arg_value(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct ExecResult {
     pub last_insert_id: u64,
     pub raws_affected: u64,
 }
 
-#[derive(Clone, Debug, PartialEq)]
+/// How the driver materializes the result set of a query.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CursorMode {
+    /// Driver default, the driver may buffer the whole result set.
+    #[default]
+    Client,
+    /// Keep the result set on the server and fetch it in batches of
+    /// [`QueryOptions::fetch_size`] rows, e.g. with a server-side cursor.
+    Server,
+}
+
+/// Query hints passed to [`Statement::query_with`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct QueryOptions {
+    /// Number of rows the driver fetches per round trip, [`None`] means driver default.
+    pub fetch_size: Option<usize>,
+    pub cursor_mode: CursorMode,
+}
+
+/// Result set column metadata.
+///
+/// Optional metadata is [`None`] if the driver doesn't know it, drivers can fill
+/// in the fields they support and use `..Default::default()` for the rest.
+#[derive(Clone, Debug, PartialEq, Default)]
 pub struct Column {
     pub column_index: u64,
     pub column_name: String,
     pub column_decltype: String,
     pub column_decltype_len: Option<u64>,
+    /// Whether the column accepts `NULL`.
+    pub column_nullable: Option<bool>,
+    /// Total number of digits of numeric column.
+    pub column_precision: Option<u32>,
+    /// Number of digits after the decimal point of numeric column.
+    pub column_scale: Option<u32>,
+    /// Name of the table the column originates from.
+    pub column_table: Option<String>,
+    /// Name of the schema/database of [`Column::column_table`].
+    pub column_schema: Option<String>,
+    /// Whether the column value is generated by an auto increment sequence.
+    pub column_auto_increment: Option<bool>,
+    /// Whether the column is part of the primary key.
+    pub column_primary_key: Option<bool>,
+    /// Column type the driver maps [`Column::column_decltype`] to.
+    pub column_type: Option<ColumnType>,
 }
 
+/// Column or placeholder type, see [`ArgValue`] for the meaning of variants.
+///
+/// Like [`ArgValue`], this enum is non exhaustive because of feature gated variants.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum ColumnType {
+    Bool,
+    I8,
+    I16,
+    I32,
     I64,
+    U8,
+    U16,
+    U32,
+    U64,
+    F32,
     F64,
     String,
     Bytes,
+    /// Exact decimal, `None` precision/scale means use the column declaration.
+    Decimal {
+        precision: Option<u32>,
+        scale: Option<u32>,
+    },
+    Date,
+    Time,
+    DateTime,
+    DateTimeUtc,
+    DateTimeTz,
+    #[cfg(feature = "uuid")]
+    Uuid,
+    #[cfg(feature = "json")]
+    Json,
+    /// Array with element type.
+    Array(Box<ColumnType>),
+    /// Row/composite with field types.
+    Composite(Vec<ColumnType>),
     Null,
 }
 
@@ -91,6 +362,28 @@ pub trait Statement: Send {
     /// executes a query that may return rows, such as a
     /// SELECT.
     fn query(&mut self, args: Vec<Argument>, callback: BoxedCallback<Box<dyn Rows>>);
+
+    /// Executes a query with fetch size and cursor hints.
+    ///
+    /// The default implementation ignores `options` and calls [`Statement::query`].
+    fn query_with(
+        &mut self,
+        args: Vec<Argument>,
+        _options: QueryOptions,
+        callback: BoxedCallback<Box<dyn Rows>>,
+    ) {
+        self.query(args, callback)
+    }
+
+    /// Release the prepared statement on the server.
+    ///
+    /// Called when the statement is closed explicitly, and before it is
+    /// dropped, in which case `callback` result is ignored. Either way the
+    /// connection is returned to the pool afterwards. The default
+    /// implementation does nothing.
+    fn close(&mut self, callback: BoxedCallback<()>) {
+        callback.invoke(Ok(()));
+    }
 }
 
 pub trait Rows: Send {
@@ -105,4 +398,194 @@ pub trait Rows: Send {
         column_type: ColumnType,
         callback: BoxedCallback<Option<ArgValue>>,
     );
+
+    /// Move to the next row and return all its column values in column order,
+    /// or [`None`] if there are no more rows.
+    ///
+    /// This optional method lets the driver return a whole row with one callback
+    /// instead of one [`Rows::next`] plus one [`Rows::get`] per column. Values
+    /// are returned as the driver's natural type for each column.
+    ///
+    /// The default implementation returns [`RDBCError::Unsupported`].
+    fn next_row(&mut self, callback: BoxedCallback<Option<Vec<Option<ArgValue>>>>) {
+        callback.invoke(Err(RDBCError::Unsupported("next_row".to_owned()).into()));
+    }
+
+    /// Move to the next result set of a multi-statement batch or stored
+    /// procedure, returns false if there are no more result sets.
+    ///
+    /// After moving, [`Rows::colunms`] returns the columns of the new result set
+    /// and [`Rows::next`] must be called before reading its first row.
+    ///
+    /// The default implementation returns false, for drivers that only return
+    /// one result set per query.
+    fn next_result_set(&mut self, callback: BoxedCallback<bool>) {
+        callback.invoke(Ok(false));
+    }
+
+    /// Read up to `len` bytes of BLOB/TEXT column `name` of the current row,
+    /// starting at byte `offset`.
+    ///
+    /// Returns an empty chunk after the end of the value and [`None`] if the
+    /// value is `NULL`. TEXT values are returned as UTF-8 bytes.
+    ///
+    /// The default implementation returns [`RDBCError::Unsupported`], callers
+    /// fall back to reading the whole value with [`Rows::get`].
+    fn read_chunk(
+        &mut self,
+        _name: ArgName,
+        _offset: u64,
+        _len: usize,
+        callback: BoxedCallback<Option<Vec<u8>>>,
+    ) {
+        callback.invoke(Err(RDBCError::Unsupported("read_chunk".to_owned()).into()));
+    }
+
+    /// Discard the rest of the result set, e.g. cancel a server-side cursor or
+    /// skip unread rows on the wire, so the connection can be reused.
+    ///
+    /// Called when the result set is closed explicitly, and before partially
+    /// read rows are dropped, in which case `callback` result is ignored. The
+    /// default implementation does nothing.
+    fn close(&mut self, callback: BoxedCallback<()>) {
+        callback.invoke(Ok(()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{FixedOffset, NaiveDate, TimeZone, Utc};
+
+    use super::{ArgValue, ColumnType};
+
+    #[test]
+    fn test_temporal_display() {
+        let date = NaiveDate::from_ymd_opt(2022, 11, 3).unwrap();
+        let datetime = date.and_hms_milli_opt(8, 30, 5, 120).unwrap();
+
+        assert_eq!(ArgValue::Date(date).to_string(), "'2022-11-03'");
+        assert_eq!(
+            ArgValue::Time(datetime.time()).to_string(),
+            "'08:30:05.120'"
+        );
+        assert_eq!(
+            ArgValue::DateTime(datetime).to_string(),
+            "'2022-11-03 08:30:05.120'"
+        );
+        assert_eq!(
+            ArgValue::DateTimeUtc(Utc.from_utc_datetime(&datetime)).to_string(),
+            "'2022-11-03 08:30:05.120+00:00'"
+        );
+        assert_eq!(
+            ArgValue::DateTimeTz(
+                FixedOffset::east_opt(8 * 3600)
+                    .unwrap()
+                    .from_local_datetime(&datetime)
+                    .unwrap()
+            )
+            .to_string(),
+            "'2022-11-03 08:30:05.120+08:00'"
+        );
+    }
+
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn test_uuid_display() {
+        let id = uuid::Uuid::from_u128(0x67e5504410b1426f9247bb680e5fe0c8);
+
+        assert_eq!(
+            ArgValue::Uuid(id).to_string(),
+            "'67e55044-10b1-426f-9247-bb680e5fe0c8'"
+        );
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_json_display() {
+        let value = serde_json::json!({ "id": 1, "tags": ["a"] });
+
+        assert_eq!(
+            ArgValue::Json(value).to_string(),
+            r#"'{"id":1,"tags":["a"]}'"#
+        );
+    }
+
+    #[test]
+    fn test_array_composite_display() {
+        let array = ArgValue::Array(ColumnType::I64, vec![ArgValue::I64(1), ArgValue::I64(2)]);
+
+        assert_eq!(array.to_string(), "ARRAY[1, 2]");
+        assert_eq!(
+            ArgValue::Array(ColumnType::String, vec![]).to_string(),
+            "ARRAY[]"
+        );
+        assert_eq!(
+            ArgValue::Composite(vec![
+                array,
+                ArgValue::String("a".to_owned()),
+                ArgValue::Null
+            ])
+            .to_string(),
+            "ROW(ARRAY[1, 2], 'a', NULL)"
+        );
+    }
+
+    #[test]
+    fn test_cast() {
+        assert_eq!(
+            ArgValue::I64(127).cast(&ColumnType::I8).unwrap(),
+            ArgValue::I8(127)
+        );
+        assert!(ArgValue::I64(128).cast(&ColumnType::I8).is_err());
+        assert!(ArgValue::I32(-1).cast(&ColumnType::U64).is_err());
+        assert!(ArgValue::U64(u64::MAX).cast(&ColumnType::I64).is_err());
+        assert_eq!(
+            ArgValue::U64(u64::MAX).cast(&ColumnType::U64).unwrap(),
+            ArgValue::U64(u64::MAX)
+        );
+        assert_eq!(
+            ArgValue::I64(1).cast(&ColumnType::Bool).unwrap(),
+            ArgValue::Bool(true)
+        );
+        assert!(ArgValue::I64(2).cast(&ColumnType::Bool).is_err());
+        assert_eq!(
+            ArgValue::Bool(true).cast(&ColumnType::U8).unwrap(),
+            ArgValue::U8(1)
+        );
+        assert!(ArgValue::F64(1.5).cast(&ColumnType::I32).is_err());
+        assert_eq!(
+            ArgValue::F64(2.0).cast(&ColumnType::I32).unwrap(),
+            ArgValue::I32(2)
+        );
+        assert!(ArgValue::F64(f64::MAX).cast(&ColumnType::F32).is_err());
+        assert!(ArgValue::U64(u64::MAX).cast(&ColumnType::F64).is_err());
+        assert!(ArgValue::I64(i64::MAX).cast(&ColumnType::F64).is_err());
+        assert!(ArgValue::I32((1 << 24) + 1).cast(&ColumnType::F32).is_err());
+        assert_eq!(
+            ArgValue::I64(1 << 53).cast(&ColumnType::F64).unwrap(),
+            ArgValue::F64(9007199254740992.0)
+        );
+        assert_eq!(
+            ArgValue::I32(-3).cast(&ColumnType::F32).unwrap(),
+            ArgValue::F32(-3.0)
+        );
+        assert_eq!(
+            ArgValue::String("1".to_owned())
+                .cast(&ColumnType::I8)
+                .unwrap(),
+            ArgValue::String("1".to_owned())
+        );
+
+        assert_eq!(
+            ArgValue::Array(ColumnType::I64, vec![ArgValue::I64(1), ArgValue::Null])
+                .cast(&ColumnType::Array(Box::new(ColumnType::I16)))
+                .unwrap(),
+            ArgValue::Array(ColumnType::I16, vec![ArgValue::I16(1), ArgValue::Null])
+        );
+        assert!(
+            ArgValue::Array(ColumnType::I64, vec![ArgValue::I64(1 << 20)])
+                .cast(&ColumnType::Array(Box::new(ColumnType::I16)))
+                .is_err()
+        );
+    }
 }
@@ -0,0 +1,295 @@
+//! Mapping from column declaration types to [`ColumnType`].
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, OnceLock},
+};
+
+use super::{Column, ColumnType};
+
+/// Registry that turns [`Column::column_decltype`] strings into [`ColumnType`].
+///
+/// Declaration types are matched case insensitively after removing length and
+/// precision arguments, so `VARCHAR(255)` is looked up as `varchar` and
+/// `INT(11) UNSIGNED` as `int unsigned`. A trailing `[]` maps to
+/// [`ColumnType::Array`] of the element type. `BIT` and `BIT(1)` map to
+/// [`ColumnType::Bool`], wider bit strings to [`ColumnType::U64`] or
+/// [`ColumnType::Bytes`] beyond 64 bits.
+///
+/// [`TypeRegistry::new`] knows the common SQL type names, drivers register
+/// their own names on top of it in [`super::Driver::type_registry`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeRegistry {
+    types: HashMap<String, ColumnType>,
+    fallback: ColumnType,
+}
+
+impl Default for TypeRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TypeRegistry {
+    /// Create registry with the common SQL type names.
+    pub fn new() -> Self {
+        let mut registry = Self::empty();
+
+        let decimal = ColumnType::Decimal {
+            precision: None,
+            scale: None,
+        };
+
+        let types = [
+            (&["bool", "boolean", "bit"][..], ColumnType::Bool),
+            (&["tinyint"], ColumnType::I8),
+            (&["smallint", "int2"], ColumnType::I16),
+            (&["int", "integer", "int4", "mediumint"], ColumnType::I32),
+            (&["bigint", "int8"], ColumnType::I64),
+            (&["tinyint unsigned"], ColumnType::U8),
+            (&["smallint unsigned"], ColumnType::U16),
+            (
+                &["int unsigned", "integer unsigned", "mediumint unsigned"],
+                ColumnType::U32,
+            ),
+            (&["bigint unsigned"], ColumnType::U64),
+            (&["real", "float4"], ColumnType::F32),
+            (
+                &["float", "float8", "double", "double precision"],
+                ColumnType::F64,
+            ),
+            (&["decimal", "numeric", "dec"], decimal),
+            (
+                &[
+                    "char",
+                    "varchar",
+                    "character",
+                    "character varying",
+                    "nchar",
+                    "nvarchar",
+                    "text",
+                    "tinytext",
+                    "mediumtext",
+                    "longtext",
+                    "clob",
+                ],
+                ColumnType::String,
+            ),
+            (
+                &[
+                    "binary",
+                    "varbinary",
+                    "blob",
+                    "tinyblob",
+                    "mediumblob",
+                    "longblob",
+                    "bytea",
+                ],
+                ColumnType::Bytes,
+            ),
+            (&["date"], ColumnType::Date),
+            (&["time", "time without time zone"], ColumnType::Time),
+            (
+                &["datetime", "timestamp", "timestamp without time zone"],
+                ColumnType::DateTime,
+            ),
+            (
+                &["timestamptz", "timestamp with time zone"],
+                ColumnType::DateTimeTz,
+            ),
+            #[cfg(feature = "uuid")]
+            (&["uuid"], ColumnType::Uuid),
+            #[cfg(feature = "json")]
+            (&["json", "jsonb"], ColumnType::Json),
+            (&["null"], ColumnType::Null),
+        ];
+
+        for (names, column_type) in types {
+            for name in names {
+                registry.register(name, column_type.clone());
+            }
+        }
+
+        registry
+    }
+
+    /// Returns the registry created by [`TypeRegistry::new`], built once and
+    /// shared by every caller.
+    pub(crate) fn shared() -> Arc<Self> {
+        static SHARED: OnceLock<Arc<TypeRegistry>> = OnceLock::new();
+
+        SHARED.get_or_init(|| Arc::new(Self::new())).clone()
+    }
+
+    /// Create registry without any type names.
+    pub fn empty() -> Self {
+        Self {
+            types: Default::default(),
+            fallback: ColumnType::String,
+        }
+    }
+
+    /// Map declaration type `decltype` to `column_type`, replacing any
+    /// previous mapping.
+    pub fn register<S>(&mut self, decltype: S, column_type: ColumnType) -> &mut Self
+    where
+        S: AsRef<str>,
+    {
+        let (name, _) = normalize(decltype.as_ref());
+
+        self.types.insert(name, column_type);
+
+        self
+    }
+
+    /// Set the type used for unknown declaration types, defaults to [`ColumnType::String`].
+    pub fn set_fallback(&mut self, column_type: ColumnType) -> &mut Self {
+        self.fallback = column_type;
+
+        self
+    }
+
+    /// Returns the type registered for `decltype`, [`None`] if it is unknown.
+    ///
+    /// Precision and scale arguments of decimal types are kept, e.g.
+    /// `NUMERIC(10,2)` maps to `Decimal { precision: Some(10), scale: Some(2) }`.
+    pub fn lookup(&self, decltype: &str) -> Option<ColumnType> {
+        let (name, args) = normalize(decltype);
+
+        if let Some(element) = name.strip_suffix("[]") {
+            return self
+                .lookup(element)
+                .map(|element| ColumnType::Array(Box::new(element)));
+        }
+
+        let column_type = self.types.get(&name)?.clone();
+
+        Some(match column_type {
+            ColumnType::Decimal {
+                precision: None,
+                scale: None,
+            } => ColumnType::Decimal {
+                precision: args.first().copied(),
+                scale: args.get(1).copied(),
+            },
+            ColumnType::Bool if name == "bit" => match args.first() {
+                Some(&bits) if bits > 64 => ColumnType::Bytes,
+                Some(&bits) if bits > 1 => ColumnType::U64,
+                _ => ColumnType::Bool,
+            },
+            column_type => column_type,
+        })
+    }
+
+    /// Returns the natural type of `column`.
+    ///
+    /// [`Column::column_type`] reported by the driver wins, otherwise
+    /// [`Column::column_decltype`] is looked up, falling back to the
+    /// registry fallback type.
+    pub fn column_type(&self, column: &Column) -> ColumnType {
+        if let Some(column_type) = &column.column_type {
+            return column_type.clone();
+        }
+
+        match self.lookup(&column.column_decltype) {
+            Some(ColumnType::Decimal { precision, scale }) => ColumnType::Decimal {
+                precision: precision.or(column.column_precision),
+                scale: scale.or(column.column_scale),
+            },
+            Some(column_type) => column_type,
+            None => self.fallback.clone(),
+        }
+    }
+}
+
+/// Lowercase `decltype`, remove parenthesized arguments and collapse
+/// whitespace. Returns the name and the numeric arguments.
+fn normalize(decltype: &str) -> (String, Vec<u32>) {
+    let mut name = String::with_capacity(decltype.len());
+    let mut args = String::new();
+    let mut depth = 0;
+
+    for c in decltype.chars() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            c if depth > 0 => args.push(c),
+            c => name.push(c.to_ascii_lowercase()),
+        }
+    }
+
+    let name = name
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .replace(" []", "[]");
+
+    let args = args
+        .split(',')
+        .filter_map(|arg| arg.trim().parse().ok())
+        .collect();
+
+    (name, args)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::driver::{Column, ColumnType};
+
+    use super::TypeRegistry;
+
+    #[test]
+    fn test_lookup() {
+        let mut registry = TypeRegistry::new();
+
+        assert_eq!(registry.lookup("VARCHAR(255)"), Some(ColumnType::String));
+        assert_eq!(registry.lookup("BIT"), Some(ColumnType::Bool));
+        assert_eq!(registry.lookup("bit(1)"), Some(ColumnType::Bool));
+        assert_eq!(registry.lookup("BIT(8)"), Some(ColumnType::U64));
+        assert_eq!(registry.lookup("BIT(128)"), Some(ColumnType::Bytes));
+        assert_eq!(registry.lookup("int(11)  Unsigned"), Some(ColumnType::U32));
+        assert_eq!(
+            registry.lookup("Timestamp(6) With Time Zone"),
+            Some(ColumnType::DateTimeTz)
+        );
+        assert_eq!(
+            registry.lookup("NUMERIC(10, 2)"),
+            Some(ColumnType::Decimal {
+                precision: Some(10),
+                scale: Some(2)
+            })
+        );
+        assert_eq!(
+            registry.lookup("int8[]"),
+            Some(ColumnType::Array(Box::new(ColumnType::I64)))
+        );
+        assert_eq!(registry.lookup("geometry"), None);
+
+        registry.register("INTEGER", ColumnType::I64);
+        assert_eq!(registry.lookup("integer"), Some(ColumnType::I64));
+
+        let mut column = Column {
+            column_decltype: "decimal".to_owned(),
+            column_precision: Some(5),
+            column_scale: Some(1),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            registry.column_type(&column),
+            ColumnType::Decimal {
+                precision: Some(5),
+                scale: Some(1)
+            }
+        );
+
+        column.column_decltype = "geometry".to_owned();
+        assert_eq!(registry.column_type(&column), ColumnType::String);
+
+        registry.set_fallback(ColumnType::Bytes);
+        assert_eq!(registry.column_type(&column), ColumnType::Bytes);
+
+        column.column_type = Some(ColumnType::F64);
+        assert_eq!(registry.column_type(&column), ColumnType::F64);
+    }
+}
@@ -5,6 +5,7 @@ use anyhow::Result;
 use crate::{driver, BoxedDriver};
 
 /// Database connection pool trait.
+#[async_trait::async_trait]
 pub trait ConnectionPool: Sized {
     /// Create new connection pool
     /// # Arguments
@@ -15,7 +16,9 @@ pub trait ConnectionPool: Sized {
         S: Into<String> + AsRef<str>;
 
     /// Get new connection from pool or create new one from driver.
-    fn get_conn(&self) -> Result<Box<dyn driver::Connection>>;
+    ///
+    /// May wait for another connection to be released if the pool is full.
+    async fn get_conn(&self) -> Result<Box<dyn driver::Connection>>;
 
     /// Release one connection return to pool.
     ///
@@ -23,4 +26,10 @@ pub trait ConnectionPool: Sized {
     ///
     /// * `conn` - Unused connection instance
     fn release_conn(&self, conn: Box<dyn driver::Connection>);
+
+    /// Returns the driver column type mapping, defaults to the shared
+    /// [`driver::TypeRegistry::new`] mapping.
+    fn type_registry(&self) -> Arc<driver::TypeRegistry> {
+        driver::TypeRegistry::shared()
+    }
 }
@@ -1,28 +1,230 @@
 //! Default [`super::ConnectionPool`] implementation.
 
-use std::sync::{Arc, Mutex};
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
 
 use async_trait::async_trait;
-use chrono::{DateTime, Utc};
+use futures::{
+    channel::oneshot,
+    future::{select, Either},
+};
+use futures_timer::Delay;
 
-use crate::{driver, BoxedDriver};
+use crate::{
+    driver::{self, RDBCError},
+    BoxedDriver,
+};
 
 use super::{driver::AsyncDriver, ConnectionPool, Preparable, Statement, Transaction};
 
 use anyhow::Result;
 
+struct IdleConn {
+    conn: Box<dyn driver::Connection>,
+    idle_since: Instant,
+}
+
+struct ConnInfo {
+    created: Instant,
+    /// Time of the last successful [`driver::Connection::ping`] or of creation.
+    validated: Instant,
+}
+
+/// Snapshot of [`Database`] pool statistics returned by [`Database::stats`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PoolStats {
+    /// Maximum number of open connections, 0 means unlimited.
+    pub max_open_conns: usize,
+    /// Number of open connections, including in use, idle and being opened ones.
+    pub open_conns: usize,
+    /// Number of open connections that are not idle, `open_conns - idle`.
+    ///
+    /// Besides connections handed out by [`ConnectionPool::get_conn`], this
+    /// counts connections still being opened and idle connections taken out
+    /// for validation.
+    pub in_use: usize,
+    /// Number of idle connections ready to be handed out.
+    pub idle: usize,
+    /// Total number of [`ConnectionPool::get_conn`] calls that waited for a connection.
+    pub wait_count: u64,
+    /// Total time spent waiting for a connection.
+    pub wait_duration: Duration,
+    /// Connections closed because of [`Database::set_max_idle_conns`].
+    pub max_idle_closed: u64,
+    /// Connections closed because of [`Database::set_max_idle_time`].
+    pub max_idle_time_closed: u64,
+    /// Connections closed because of [`Database::set_max_lifetime`].
+    pub max_lifetime_closed: u64,
+    /// Number of failed [`driver::Driver::open_async`] calls.
+    pub open_failures: u64,
+}
+
+/// Minimum period of [`Database::run_maintenance`].
+const MIN_MAINTENANCE_INTERVAL: Duration = Duration::from_secs(1);
+
+/// What a waiting [`ConnectionPool::get_conn`] call receives.
+enum Grant {
+    /// Connection released by another user.
+    Conn(Box<dyn driver::Connection>),
+    /// Permission to open a new connection, the open slot is already counted.
+    Open,
+}
+
+/// Pool state shared by all clones of [`Database`].
+struct Pool {
+    /// Idle connections, the least recently released first.
+    idle: Vec<IdleConn>,
+    /// Every open connection by [`driver::Connection::id`].
+    conns: HashMap<String, ConnInfo>,
+    /// Number of open connections, including in use and being opened ones.
+    open: usize,
+    /// Pending [`ConnectionPool::get_conn`] calls, served first come first served.
+    waiters: VecDeque<oneshot::Sender<Grant>>,
+    max_open_conns: usize,
+    max_idle_conns: usize,
+    max_lifetime: Option<Duration>,
+    max_idle_time: Option<Duration>,
+    acquire_timeout: Option<Duration>,
+    test_on_borrow: bool,
+    test_while_idle: bool,
+    validation_interval: Duration,
+    /// Cumulative counters of [`PoolStats`].
+    stats: PoolStats,
+}
+
+impl Pool {
+    fn is_full(&self) -> bool {
+        self.max_open_conns != 0 && self.open >= self.max_open_conns
+    }
+
+    /// Hand `conn` to the oldest waiter, returns it back if nobody waits.
+    fn hand_over(
+        &mut self,
+        mut conn: Box<dyn driver::Connection>,
+    ) -> Option<Box<dyn driver::Connection>> {
+        while let Some(waiter) = self.waiters.pop_front() {
+            match waiter.send(Grant::Conn(conn)) {
+                Ok(()) => return None,
+                Err(Grant::Conn(returned)) => conn = returned,
+                Err(Grant::Open) => unreachable!(),
+            }
+        }
+
+        Some(conn)
+    }
+
+    /// Let waiters open new connections while the pool has free slots.
+    fn grant_open(&mut self) {
+        while !self.is_full() {
+            let waiter = match self.waiters.pop_front() {
+                Some(waiter) => waiter,
+                None => return,
+            };
+
+            if waiter.send(Grant::Open).is_ok() {
+                self.open += 1;
+            }
+        }
+    }
+
+    /// Free the slot of a closed or never opened connection.
+    fn release_slot(&mut self) {
+        self.open -= 1;
+        self.grant_open();
+    }
+
+    fn expired(&self, conn: &dyn driver::Connection, now: Instant) -> bool {
+        match (self.max_lifetime, self.conns.get(conn.id())) {
+            (Some(max_lifetime), Some(info)) => now.duration_since(info.created) >= max_lifetime,
+            _ => false,
+        }
+    }
+
+    /// Returns true if `conn` wasn't validated within `validation_interval`.
+    fn needs_validation(&self, conn: &dyn driver::Connection, now: Instant) -> bool {
+        match self.conns.get(conn.id()) {
+            Some(info) => now.duration_since(info.validated) >= self.validation_interval,
+            None => true,
+        }
+    }
+
+    fn idle_expired(&self, idle: &IdleConn, now: Instant) -> bool {
+        match self.max_idle_time {
+            Some(max_idle_time) => now.duration_since(idle.idle_since) >= max_idle_time,
+            None => false,
+        }
+    }
+
+    /// Remove the connection from the pool and free its slot.
+    ///
+    /// Returns the connection, the caller closes it by dropping it after the
+    /// pool lock is released, so a slow driver shutdown doesn't block other
+    /// pool users.
+    #[must_use]
+    fn close(&mut self, conn: Box<dyn driver::Connection>) -> Box<dyn driver::Connection> {
+        self.conns.remove(conn.id());
+        self.release_slot();
+
+        conn
+    }
+
+    /// Remove idle connections past their lifetime or idle timeout, and the
+    /// oldest ones above `max_idle_conns`.
+    ///
+    /// Returns the removed connections, see [`Pool::close`].
+    #[must_use]
+    fn purge(&mut self, now: Instant) -> Vec<Box<dyn driver::Connection>> {
+        let idle = std::mem::take(&mut self.idle);
+
+        let mut closed = vec![];
+
+        for idle in idle {
+            if self.expired(idle.conn.as_ref(), now) {
+                self.stats.max_lifetime_closed += 1;
+                closed.push(self.close(idle.conn));
+            } else if self.idle_expired(&idle, now) {
+                self.stats.max_idle_time_closed += 1;
+                closed.push(self.close(idle.conn));
+            } else {
+                self.idle.push(idle);
+            }
+        }
+
+        while self.idle.len() > self.max_idle_conns {
+            let idle = self.idle.remove(0);
+
+            self.stats.max_idle_closed += 1;
+            closed.push(self.close(idle.conn));
+        }
+
+        closed
+    }
+}
+
 /// Default [`super::ConnectionPool`] implementation.
+///
+/// Connections older than [`Database::set_max_lifetime`] or idle longer than
+/// [`Database::set_max_idle_time`] are closed instead of being handed out.
+/// Once [`Database::set_max_open_conns`] connections are open, callers wait
+/// in a queue for a released connection.
+///
+/// Idle connections can be checked with [`driver::Connection::ping`] before
+/// they are handed out, see [`Database::set_test_on_borrow`], or periodically
+/// by [`Database::run_maintenance`], see [`Database::set_test_while_idle`].
 #[derive(Clone)]
 #[allow(dead_code)]
 pub struct Database {
     driver: Arc<Mutex<BoxedDriver>>,
     url: String,
-    conns: Arc<Mutex<Vec<(DateTime<Utc>, Box<dyn driver::Connection>)>>>,
-    max_idle_conns: usize,
-    max_lifetime: chrono::Duration,
+    pool: Arc<Mutex<Pool>>,
+    types: Arc<driver::TypeRegistry>,
     _driver_name: String,
 }
 
+#[async_trait]
 impl ConnectionPool for Database {
     /// Implement [`super::ConnectionPool::new`]
     fn new<S>(driver_name: S, driver: Arc<Mutex<BoxedDriver>>, url: S) -> anyhow::Result<Self>
@@ -31,58 +233,463 @@ impl ConnectionPool for Database {
     {
         let url: String = url.into();
         let driver_name = driver_name.into();
+        let types = Arc::new(driver.lock().unwrap().type_registry());
 
         Ok(Self {
             driver,
             url,
-            conns: Default::default(),
-            max_idle_conns: 100,
-            max_lifetime: chrono::Duration::hours(1),
+            pool: Arc::new(Mutex::new(Pool {
+                idle: vec![],
+                conns: Default::default(),
+                open: 0,
+                waiters: Default::default(),
+                max_open_conns: 0,
+                max_idle_conns: 100,
+                max_lifetime: Some(Duration::from_secs(60 * 60)),
+                max_idle_time: None,
+                acquire_timeout: None,
+                test_on_borrow: false,
+                test_while_idle: false,
+                validation_interval: Duration::from_secs(30),
+                stats: Default::default(),
+            })),
+            types,
             _driver_name: driver_name,
         })
     }
     /// Implement [`super::ConnectionPool::get_conn`]
-    fn get_conn(&self) -> anyhow::Result<Box<dyn driver::Connection>> {
-        let mut conns = self.conns.lock().unwrap();
+    async fn get_conn(&self) -> anyhow::Result<Box<dyn driver::Connection>> {
+        loop {
+            let (acquire, closed) = {
+                let mut pool = self.pool.lock().unwrap();
 
-        if !conns.is_empty() {
-            let (_, conn) = conns.remove(0);
-            return Ok(conn);
-        }
+                let now = Instant::now();
 
-        let conn = self.driver.lock().unwrap().open(&self.url)?;
+                let closed = pool.purge(now);
 
-        Ok(conn)
+                let acquire = if !pool.idle.is_empty() {
+                    let conn = pool.idle.remove(0).conn;
+                    let validate = pool.test_on_borrow && pool.needs_validation(conn.as_ref(), now);
+
+                    Acquire::Idle(conn, validate)
+                } else if !pool.is_full() {
+                    pool.open += 1;
+
+                    Acquire::Open
+                } else {
+                    let (sender, receiver) = oneshot::channel();
+
+                    pool.waiters.push_back(sender);
+                    pool.stats.wait_count += 1;
+
+                    Acquire::Wait(receiver, pool.acquire_timeout)
+                };
+
+                (acquire, closed)
+            };
+
+            drop(closed);
+
+            let grant = match acquire {
+                Acquire::Idle(conn, false) => return Ok(conn),
+                Acquire::Idle(conn, true) => match self.validate(conn).await {
+                    Some(conn) => return Ok(conn),
+                    None => continue,
+                },
+                Acquire::Open => Grant::Open,
+                Acquire::Wait(receiver, acquire_timeout) => {
+                    Waiter {
+                        db: self,
+                        receiver,
+                        since: Instant::now(),
+                    }
+                    .wait(acquire_timeout)
+                    .await?
+                }
+            };
+
+            return match grant {
+                Grant::Conn(conn) => Ok(conn),
+                Grant::Open => self.open_conn().await,
+            };
+        }
     }
 
     /// Implement [`super::ConnectionPool::release_conn`]
     fn release_conn(&self, conn: Box<dyn driver::Connection>) {
-        let mut conns = self.conns.lock().unwrap();
+        let closed = {
+            let mut pool = self.pool.lock().unwrap();
 
-        if conns.len() == self.max_idle_conns {
-            conns.remove(0);
+            let now = Instant::now();
+
+            if matches!(conn.conn_status(), driver::ConnStatus::Disconnected) {
+                vec![pool.close(conn)]
+            } else if pool.expired(conn.as_ref(), now) {
+                pool.stats.max_lifetime_closed += 1;
+                vec![pool.close(conn)]
+            } else if let Some(conn) = pool.hand_over(conn) {
+                pool.idle.push(IdleConn {
+                    conn,
+                    idle_since: now,
+                });
+
+                pool.purge(now)
+            } else {
+                vec![]
+            }
+        };
+
+        drop(closed);
+    }
+
+    /// Implement [`super::ConnectionPool::type_registry`]
+    fn type_registry(&self) -> Arc<driver::TypeRegistry> {
+        self.types.clone()
+    }
+}
+
+/// Next step of [`ConnectionPool::get_conn`].
+enum Acquire {
+    /// Idle connection and whether it must be validated first.
+    Idle(Box<dyn driver::Connection>, bool),
+    Open,
+    Wait(oneshot::Receiver<Grant>, Option<Duration>),
+}
+
+/// Connection taken out of the pool for validation, or borrowed by
+/// [`Database::begin`] and [`Preparable::prepare`], closed on drop unless
+/// taken back.
+///
+/// Dropping it means the caller gave up while the driver may still be working
+/// on the connection, so it is not reused.
+struct PendingConn<'a> {
+    db: &'a Database,
+    conn: Option<Box<dyn driver::Connection>>,
+}
+
+impl<'a> PendingConn<'a> {
+    fn get(&mut self) -> &mut dyn driver::Connection {
+        self.conn.as_mut().unwrap().as_mut()
+    }
+
+    fn take(mut self) -> Box<dyn driver::Connection> {
+        self.conn.take().unwrap()
+    }
+}
+
+impl<'a> Drop for PendingConn<'a> {
+    fn drop(&mut self) {
+        if let Some(conn) = self.conn.take() {
+            let conn = self.db.pool.lock().unwrap().close(conn);
+
+            drop(conn);
+        }
+    }
+}
+
+/// Pending [`ConnectionPool::get_conn`] call.
+///
+/// A grant sent after the caller gave up, by timeout or by dropping the
+/// future, is returned to the pool on drop so the slot is not lost.
+struct Waiter<'a> {
+    db: &'a Database,
+    receiver: oneshot::Receiver<Grant>,
+    since: Instant,
+}
+
+impl<'a> Waiter<'a> {
+    async fn wait(mut self, acquire_timeout: Option<Duration>) -> Result<Grant> {
+        let grant = match acquire_timeout {
+            Some(acquire_timeout) => {
+                match select(&mut self.receiver, Delay::new(acquire_timeout)).await {
+                    Either::Left((grant, _)) => grant,
+                    Either::Right(_) => {
+                        return Err(RDBCError::Timeout("acquiring connection".to_owned()).into())
+                    }
+                }
+            }
+            None => (&mut self.receiver).await,
+        };
+
+        Ok(grant.map_err(|_| RDBCError::Closed("connection pool".to_owned()))?)
+    }
+}
+
+impl<'a> Drop for Waiter<'a> {
+    fn drop(&mut self) {
+        self.db.pool.lock().unwrap().stats.wait_duration += self.since.elapsed();
+
+        self.receiver.close();
+
+        match self.receiver.try_recv() {
+            Ok(Some(Grant::Conn(conn))) => self.db.release_conn(conn),
+            Ok(Some(Grant::Open)) => self.db.pool.lock().unwrap().release_slot(),
+            _ => {}
         }
+    }
+}
+
+/// Slot counted for a connection being opened, freed on drop unless the
+/// connection is opened.
+struct OpeningSlot<'a> {
+    db: Option<&'a Database>,
+}
+
+impl<'a> OpeningSlot<'a> {
+    fn opened(mut self, conn: &dyn driver::Connection) {
+        if let Some(db) = self.db.take() {
+            let now = Instant::now();
 
-        conns.push((Utc::now(), conn));
+            db.pool.lock().unwrap().conns.insert(
+                conn.id().to_owned(),
+                ConnInfo {
+                    created: now,
+                    validated: now,
+                },
+            );
+        }
+    }
+}
+
+impl<'a> Drop for OpeningSlot<'a> {
+    fn drop(&mut self) {
+        if let Some(db) = self.db.take() {
+            db.pool.lock().unwrap().release_slot();
+        }
     }
 }
 
 impl Database {
+    /// Open a new connection in a slot already counted by [`Pool::open`].
+    ///
+    /// The driver lock is only held while starting [`driver::Driver::open_async`],
+    /// so connections are opened concurrently.
+    async fn open_conn(&self) -> Result<Box<dyn driver::Connection>> {
+        let async_driver = AsyncDriver::new();
+
+        self.driver
+            .lock()
+            .unwrap()
+            .open_async(&self.url, async_driver.callback());
+
+        let slot = OpeningSlot { db: Some(self) };
+
+        let conn = match async_driver.await {
+            Ok(conn) => conn,
+            Err(err) => {
+                self.pool.lock().unwrap().stats.open_failures += 1;
+                return Err(err);
+            }
+        };
+
+        slot.opened(conn.as_ref());
+
+        Ok(conn)
+    }
+
+    /// Ping `conn`, returns it if it is usable or closes it.
+    async fn validate(
+        &self,
+        conn: Box<dyn driver::Connection>,
+    ) -> Option<Box<dyn driver::Connection>> {
+        let mut pending = PendingConn {
+            db: self,
+            conn: Some(conn),
+        };
+
+        let async_driver = AsyncDriver::new();
+
+        pending.conn.as_mut()?.ping(async_driver.callback());
+
+        async_driver.await.ok()?;
+
+        let conn = pending.conn.take()?;
+
+        if let Some(info) = self.pool.lock().unwrap().conns.get_mut(conn.id()) {
+            info.validated = Instant::now();
+        }
+
+        Some(conn)
+    }
+
+    /// Validate idle connections not validated within the validation interval,
+    /// returns the number of closed connections.
+    async fn validate_idle(&self) -> usize {
+        let (candidates, closed) = {
+            let mut pool = self.pool.lock().unwrap();
+
+            let now = Instant::now();
+
+            let closed = pool.purge(now);
+
+            let (candidates, idle) = std::mem::take(&mut pool.idle)
+                .into_iter()
+                .partition::<Vec<_>, _>(|idle| pool.needs_validation(idle.conn.as_ref(), now));
+
+            pool.idle = idle;
+
+            (candidates, closed)
+        };
+
+        drop(closed);
+
+        let mut closed = 0;
+
+        for IdleConn { conn, idle_since } in candidates {
+            let conn = match self.validate(conn).await {
+                Some(conn) => conn,
+                None => {
+                    closed += 1;
+                    continue;
+                }
+            };
+
+            let mut pool = self.pool.lock().unwrap();
+
+            if let Some(conn) = pool.hand_over(conn) {
+                pool.idle.push(IdleConn { conn, idle_since });
+                pool.idle.sort_by_key(|idle| idle.idle_since);
+            }
+        }
+
+        closed
+    }
+
+    /// Run pool housekeeping every validation interval, at least one second,
+    /// until the returned future is dropped.
+    ///
+    /// Expired idle connections are closed, and idle connections are pinged if
+    /// [`Database::set_test_while_idle`] is on. The pool doesn't spawn tasks,
+    /// spawn this future on the application runtime to enable it.
+    pub async fn run_maintenance(&self) {
+        loop {
+            let (interval, test_while_idle) = {
+                let pool = self.pool.lock().unwrap();
+
+                (pool.validation_interval, pool.test_while_idle)
+            };
+
+            Delay::new(interval.max(MIN_MAINTENANCE_INTERVAL)).await;
+
+            if test_while_idle {
+                self.validate_idle().await;
+            } else {
+                let closed = self.pool.lock().unwrap().purge(Instant::now());
+
+                drop(closed);
+            }
+        }
+    }
+
+    /// Returns a snapshot of the pool statistics.
+    pub fn stats(&self) -> PoolStats {
+        let pool = self.pool.lock().unwrap();
+
+        PoolStats {
+            max_open_conns: pool.max_open_conns,
+            open_conns: pool.open,
+            in_use: pool.open - pool.idle.len(),
+            idle: pool.idle.len(),
+            ..pool.stats.clone()
+        }
+    }
+
+    /// Ping idle connections before handing them out, defaults to false.
+    pub fn set_test_on_borrow(&self, test_on_borrow: bool) {
+        self.pool.lock().unwrap().test_on_borrow = test_on_borrow;
+    }
+
+    /// Ping idle connections in [`Database::run_maintenance`], defaults to false.
+    pub fn set_test_while_idle(&self, test_while_idle: bool) {
+        self.pool.lock().unwrap().test_while_idle = test_while_idle;
+    }
+
+    /// Set the minimum time between two validations of the same connection,
+    /// defaults to 30 seconds. Connections validated more recently are handed
+    /// out without a ping.
+    pub fn set_validation_interval(&self, validation_interval: Duration) {
+        self.pool.lock().unwrap().validation_interval = validation_interval;
+    }
+
+    /// Set the maximum number of open connections, defaults to 0 which means
+    /// unlimited.
+    pub fn set_max_open_conns(&self, max_open_conns: usize) {
+        let mut pool = self.pool.lock().unwrap();
+
+        pool.max_open_conns = max_open_conns;
+        pool.grant_open();
+    }
+
+    /// Set how long [`ConnectionPool::get_conn`] waits for a connection when
+    /// the pool is full before returning [`RDBCError::Timeout`], defaults to
+    /// [`None`] which means wait forever.
+    pub fn set_acquire_timeout(&self, acquire_timeout: Option<Duration>) {
+        self.pool.lock().unwrap().acquire_timeout = acquire_timeout;
+    }
+
+    /// Set the maximum number of idle connections kept in the pool, defaults to 100.
+    pub fn set_max_idle_conns(&self, max_idle_conns: usize) {
+        let closed = {
+            let mut pool = self.pool.lock().unwrap();
+
+            pool.max_idle_conns = max_idle_conns;
+            pool.purge(Instant::now())
+        };
+
+        drop(closed);
+    }
+
+    /// Set the maximum time a connection may be reused, defaults to one hour.
+    ///
+    /// [`None`] means connections are reused forever.
+    pub fn set_max_lifetime(&self, max_lifetime: Option<Duration>) {
+        let closed = {
+            let mut pool = self.pool.lock().unwrap();
+
+            pool.max_lifetime = max_lifetime;
+            pool.purge(Instant::now())
+        };
+
+        drop(closed);
+    }
+
+    /// Set the maximum time a connection may be idle, defaults to [`None`]
+    /// which means idle connections are never closed for being idle.
+    pub fn set_max_idle_time(&self, max_idle_time: Option<Duration>) {
+        let closed = {
+            let mut pool = self.pool.lock().unwrap();
+
+            pool.max_idle_time = max_idle_time;
+            pool.purge(Instant::now())
+        };
+
+        drop(closed);
+    }
+
     /// Start new transaction
     pub async fn begin(&self) -> Result<Transaction<Database>> {
-        let mut conn = self.get_conn()?;
+        let mut conn = PendingConn {
+            db: self,
+            conn: Some(self.get_conn().await?),
+        };
 
         let async_driver = AsyncDriver::new();
 
-        conn.begin(async_driver.callback());
+        conn.get().begin(async_driver.callback());
 
-        let tx = async_driver.await?;
+        let tx = match async_driver.await {
+            Ok(tx) => tx,
+            Err(err) => {
+                self.release_conn(conn.take());
+                return Err(err);
+            }
+        };
 
         Ok(Transaction::new(
             self._driver_name.clone(),
             self.url.clone(),
             tx,
-            Some(conn),
+            Some(conn.take()),
             self.clone(),
         ))
     }
@@ -95,15 +702,24 @@ impl Preparable for Database {
     where
         S: Into<String> + Send,
     {
-        let mut conn = self.get_conn()?;
+        let mut conn = PendingConn {
+            db: self,
+            conn: Some(self.get_conn().await?),
+        };
 
         let async_driver = AsyncDriver::new();
 
-        conn.prepare(query.into(), async_driver.callback());
+        conn.get().prepare(query.into(), async_driver.callback());
 
-        let stmt = async_driver.await?;
+        let stmt = match async_driver.await {
+            Ok(stmt) => stmt,
+            Err(err) => {
+                self.release_conn(conn.take());
+                return Err(err);
+            }
+        };
 
-        Ok(Statement::new(Some(self.clone()), Some(conn), stmt))
+        Ok(Statement::new(Some(self.clone()), Some(conn.take()), stmt))
     }
 
     fn driver_name(&self) -> &str {
@@ -114,3 +730,224 @@ impl Preparable for Database {
         &self.url
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::atomic::Ordering, time::Duration};
+
+    use crate::{
+        driver::{
+            mock::{self, MockTable},
+            RDBCError,
+        },
+        ConnectionPool, PoolStats, Preparable,
+    };
+
+    /// Get and release one connection, returns its id.
+    async fn reuse(db: &crate::Database) -> String {
+        let conn = db.get_conn().await.unwrap();
+        let id = conn.id().to_owned();
+
+        db.release_conn(conn);
+
+        id
+    }
+
+    #[async_std::test]
+    async fn test_expiry() {
+        let db = mock::open(MockTable::new(&[], vec![]));
+
+        let id = reuse(&db).await;
+        assert_eq!(reuse(&db).await, id);
+
+        db.set_max_lifetime(Some(Duration::ZERO));
+        let id = reuse(&db).await;
+        assert_ne!(reuse(&db).await, id);
+
+        db.set_max_lifetime(None);
+        db.set_max_idle_time(Some(Duration::from_millis(20)));
+        let id = reuse(&db).await;
+        assert_eq!(reuse(&db).await, id);
+
+        async_std::task::sleep(Duration::from_millis(30)).await;
+        assert_ne!(reuse(&db).await, id);
+
+        db.set_max_idle_time(None);
+        db.set_max_idle_conns(0);
+        let id = reuse(&db).await;
+        assert_ne!(reuse(&db).await, id);
+        assert!(db.pool.lock().unwrap().conns.is_empty());
+    }
+
+    #[async_std::test]
+    async fn test_max_open_conns() {
+        let db = mock::open(MockTable::new(&[], vec![]));
+
+        db.set_max_open_conns(1);
+        db.set_acquire_timeout(Some(Duration::from_millis(20)));
+
+        let conn = db.get_conn().await.unwrap();
+
+        let err = db.get_conn().await.err().unwrap();
+        assert!(matches!(err.downcast_ref(), Some(RDBCError::Timeout(_))));
+
+        db.set_acquire_timeout(None);
+
+        let waiters = (0..2)
+            .map(|i| {
+                let db = db.clone();
+
+                async_std::task::spawn(async move {
+                    // Queue the waiters in order.
+                    async_std::task::sleep(Duration::from_millis(10 * i)).await;
+
+                    let conn = db.get_conn().await.unwrap();
+                    let id = conn.id().to_owned();
+
+                    async_std::task::sleep(Duration::from_millis(10)).await;
+                    db.release_conn(conn);
+
+                    id
+                })
+            })
+            .collect::<Vec<_>>();
+
+        async_std::task::sleep(Duration::from_millis(30)).await;
+
+        let id = conn.id().to_owned();
+        db.release_conn(conn);
+
+        for waiter in waiters {
+            assert_eq!(waiter.await, id);
+        }
+
+        // Raising the limit wakes waiters without waiting for a release.
+        let conn = db.get_conn().await.unwrap();
+
+        let waiter = {
+            let db = db.clone();
+
+            async_std::task::spawn(async move { db.get_conn().await.unwrap().id().to_owned() })
+        };
+
+        async_std::task::sleep(Duration::from_millis(10)).await;
+        db.set_max_open_conns(2);
+
+        assert_ne!(waiter.await, conn.id());
+    }
+
+    #[async_std::test]
+    async fn test_cancel_prepare() {
+        let table = MockTable::new(&[], vec![]);
+        let pending = table.pending.clone();
+
+        let mut db = mock::open(table);
+
+        db.set_max_open_conns(1);
+        db.set_acquire_timeout(Some(Duration::from_millis(20)));
+
+        let id = reuse(&db).await;
+
+        pending.store(true, Ordering::SeqCst);
+
+        // Connections of cancelled calls are closed, not reused.
+        let prepare = async_std::future::timeout(Duration::from_millis(10), db.prepare("SELECT"));
+        assert!(prepare.await.is_err());
+
+        let id2 = reuse(&db).await;
+        assert_ne!(id2, id);
+
+        let begin = async_std::future::timeout(Duration::from_millis(10), db.begin());
+        assert!(begin.await.is_err());
+
+        assert_ne!(reuse(&db).await, id2);
+
+        pending.store(false, Ordering::SeqCst);
+
+        assert!(db.prepare("SELECT").await.is_ok());
+        assert_eq!(db.stats().in_use, 0);
+    }
+
+    #[async_std::test]
+    async fn test_validation() {
+        let table = MockTable::new(&[], vec![]);
+        let disconnected = table.disconnected.clone();
+
+        let db = mock::open(table);
+
+        db.set_test_on_borrow(true);
+
+        let id = reuse(&db).await;
+        assert_eq!(reuse(&db).await, id);
+
+        // Recently validated connections are not pinged.
+        let conn = db.get_conn().await.unwrap();
+        db.release_conn(conn);
+        disconnected.store(true, Ordering::SeqCst);
+        let conn = db.get_conn().await.unwrap();
+        assert_eq!(conn.id(), id);
+
+        disconnected.store(false, Ordering::SeqCst);
+        db.release_conn(conn);
+        db.set_validation_interval(Duration::ZERO);
+        disconnected.store(true, Ordering::SeqCst);
+
+        let conn = db.get_conn().await.unwrap();
+        assert_ne!(conn.id(), id);
+        assert_eq!(db.pool.lock().unwrap().open, 1);
+
+        disconnected.store(false, Ordering::SeqCst);
+        db.release_conn(conn);
+        db.set_test_on_borrow(false);
+        disconnected.store(true, Ordering::SeqCst);
+
+        assert_eq!(db.validate_idle().await, 1);
+        assert!(db.pool.lock().unwrap().idle.is_empty());
+        assert_eq!(db.pool.lock().unwrap().open, 0);
+    }
+
+    #[async_std::test]
+    async fn test_stats() {
+        let table = MockTable::new(&[], vec![]);
+        let fail_open = table.fail_open.clone();
+
+        let db = mock::open(table);
+
+        db.set_max_open_conns(2);
+        db.set_acquire_timeout(Some(Duration::from_millis(10)));
+
+        let conn1 = db.get_conn().await.unwrap();
+        let conn2 = db.get_conn().await.unwrap();
+        assert!(db.get_conn().await.is_err());
+
+        db.release_conn(conn1);
+
+        let stats = db.stats();
+
+        assert_eq!(
+            stats,
+            PoolStats {
+                max_open_conns: 2,
+                open_conns: 2,
+                in_use: 1,
+                idle: 1,
+                wait_count: 1,
+                wait_duration: stats.wait_duration,
+                ..Default::default()
+            }
+        );
+        assert!(stats.wait_duration >= Duration::from_millis(10));
+
+        db.set_max_lifetime(Some(Duration::ZERO));
+        db.release_conn(conn2);
+
+        fail_open.store(true, Ordering::SeqCst);
+        assert!(db.get_conn().await.is_err());
+
+        let stats = db.stats();
+
+        assert_eq!(stats.open_conns, 0);
+        assert_eq!(stats.max_lifetime_closed, 2);
+        assert_eq!(stats.open_failures, 1);
+    }
+}
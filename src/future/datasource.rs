@@ -64,7 +64,7 @@ impl DataSource {
         if let Some(driver) = driver {
             DB::new(name, driver.clone(), url)
         } else {
-            return Err(anyhow!("driver {} not found", name.as_ref()));
+            Err(anyhow!("driver {} not found", name.as_ref()))
         }
     }
 
@@ -82,13 +82,10 @@ mod global {
 
     use super::*;
 
-    fn global_datasource() -> &'static mut DataSource {
-        static mut CONF: std::mem::MaybeUninit<DataSource> = std::mem::MaybeUninit::uninit();
-        static ONCE: std::sync::Once = std::sync::Once::new();
-        ONCE.call_once(|| unsafe {
-            CONF.as_mut_ptr().write(DataSource::default());
-        });
-        unsafe { &mut *CONF.as_mut_ptr() }
+    fn global_datasource() -> &'static DataSource {
+        static CONF: std::sync::OnceLock<DataSource> = std::sync::OnceLock::new();
+
+        CONF.get_or_init(DataSource::default)
     }
 
     pub fn register<S>(name: S, driver: impl driver::Driver + 'static) -> Result<()>
@@ -21,7 +21,7 @@ pub struct AsyncDriver<Output> {
     inner: Arc<Mutex<AsyncDriverImpl<Output>>>,
 }
 
-impl<Output> AsyncDriver<Output> {
+impl<Output: Send + 'static> AsyncDriver<Output> {
     pub fn new() -> Self {
         Self {
             inner: Arc::new(Mutex::new(Default::default())),
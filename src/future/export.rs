@@ -0,0 +1,453 @@
+use std::fmt::Write as _;
+
+use anyhow::Result;
+use futures::{io::AsyncWrite, AsyncWriteExt};
+
+use crate::driver;
+
+use super::{ConnectionPool, Rows};
+
+type Column = driver::Column;
+type ArgValue = driver::ArgValue;
+
+/// Output format of [`Rows::export`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExportFormat {
+    /// RFC 4180 CSV, fields are quoted only when needed.
+    #[default]
+    Csv,
+    /// One JSON object per line, keyed by column name.
+    JsonLines,
+    /// GitHub flavored Markdown table.
+    Markdown,
+    /// Aligned ASCII table with `+---+` borders.
+    Table,
+}
+
+/// How [`ArgValue::Bytes`] values are written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BytesFormat {
+    /// Lowercase hex digits, two per byte.
+    #[default]
+    Hex,
+    /// Standard base64 with padding.
+    Base64,
+    /// `<N bytes>` placeholder, the content is not written.
+    Length,
+}
+
+/// Options of [`Rows::export`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportOptions {
+    pub format: ExportFormat,
+    /// Write the column names as the first CSV line, table formats always
+    /// have a header.
+    pub header: bool,
+    /// Text written for SQL `NULL`, JSON Lines always writes `null`.
+    pub null: String,
+    pub bytes: BytesFormat,
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        Self {
+            format: ExportFormat::Csv,
+            header: true,
+            null: String::new(),
+            bytes: BytesFormat::Hex,
+        }
+    }
+}
+
+impl From<ExportFormat> for ExportOptions {
+    fn from(format: ExportFormat) -> Self {
+        Self {
+            format,
+            ..Default::default()
+        }
+    }
+}
+
+impl<DB> Rows<DB>
+where
+    DB: ConnectionPool + Sync + Send,
+{
+    /// Write the remaining rows to `writer`, returns the number of rows written.
+    ///
+    /// CSV and JSON Lines are written row by row, Markdown and ASCII tables are
+    /// buffered in memory to compute column widths.
+    pub async fn export<W>(&mut self, writer: &mut W, options: &ExportOptions) -> Result<u64>
+    where
+        W: AsyncWrite + Unpin + ?Sized,
+    {
+        let columns = self.colunms().await?;
+
+        let mut count = 0;
+
+        match options.format {
+            ExportFormat::Csv => {
+                if options.header {
+                    let names = columns.iter().map(|c| c.column_name.clone()).collect();
+
+                    writer.write_all(csv_line(names).as_bytes()).await?;
+                }
+
+                while let Some(row) = self.next_row().await? {
+                    let fields = row.values().iter().map(|v| text(v, options)).collect();
+
+                    writer.write_all(csv_line(fields).as_bytes()).await?;
+
+                    count += 1;
+                }
+            }
+            ExportFormat::JsonLines => {
+                while let Some(row) = self.next_row().await? {
+                    let line = json_line(&columns, row.values(), options);
+
+                    writer.write_all(line.as_bytes()).await?;
+
+                    count += 1;
+                }
+            }
+            ExportFormat::Markdown | ExportFormat::Table => {
+                let mut cells = vec![];
+
+                while let Some(row) = self.next_row().await? {
+                    cells.push(
+                        row.values()
+                            .iter()
+                            .map(|v| table_cell(text(v, options), options.format))
+                            .collect(),
+                    );
+                }
+
+                count = cells.len() as u64;
+
+                let table = render_table(&columns, cells, options.format);
+
+                writer.write_all(table.as_bytes()).await?;
+            }
+        }
+
+        writer.flush().await?;
+
+        Ok(count)
+    }
+}
+
+/// Format value as plain text for CSV and table formats.
+fn text(value: &Option<ArgValue>, options: &ExportOptions) -> String {
+    let value = match value {
+        Some(ArgValue::Null) | None => return options.null.clone(),
+        Some(value) => value,
+    };
+
+    match value {
+        ArgValue::Bool(v) => v.to_string(),
+        ArgValue::String(v) => v.clone(),
+        ArgValue::Bytes(v) => bytes(v, options.bytes),
+        ArgValue::Date(v) => v.format("%Y-%m-%d").to_string(),
+        ArgValue::Time(v) => v.format("%H:%M:%S%.f").to_string(),
+        ArgValue::DateTime(v) => v.format("%Y-%m-%d %H:%M:%S%.f").to_string(),
+        ArgValue::DateTimeUtc(v) => v.format("%Y-%m-%d %H:%M:%S%.f%:z").to_string(),
+        ArgValue::DateTimeTz(v) => v.format("%Y-%m-%d %H:%M:%S%.f%:z").to_string(),
+        #[cfg(feature = "uuid")]
+        ArgValue::Uuid(v) => v.to_string(),
+        #[cfg(feature = "json")]
+        ArgValue::Json(v) => v.to_string(),
+        value => value.to_string(),
+    }
+}
+
+fn bytes(value: &[u8], format: BytesFormat) -> String {
+    match format {
+        BytesFormat::Hex => value.iter().fold(String::new(), |mut s, b| {
+            let _ = write!(s, "{:02x}", b);
+            s
+        }),
+        BytesFormat::Base64 => base64(value),
+        BytesFormat::Length => format!("<{} bytes>", value.len()),
+    }
+}
+
+fn base64(value: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut s = String::with_capacity(value.len().div_ceil(3) * 4);
+
+    for chunk in value.chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |n, (i, b)| n | (*b as u32) << (16 - 8 * i));
+
+        for i in 0..4 {
+            if i <= chunk.len() {
+                s.push(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                s.push('=');
+            }
+        }
+    }
+
+    s
+}
+
+fn csv_line(fields: Vec<String>) -> String {
+    let mut line = fields
+        .into_iter()
+        .map(|field| {
+            if field.contains([',', '"', '\r', '\n']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+
+    line.push('\n');
+
+    line
+}
+
+fn json_line(columns: &[Column], values: &[Option<ArgValue>], options: &ExportOptions) -> String {
+    let mut line = String::from("{");
+
+    for (i, (column, value)) in columns.iter().zip(values).enumerate() {
+        if i > 0 {
+            line.push(',');
+        }
+
+        json_string(&column.column_name, &mut line);
+        line.push(':');
+
+        match value {
+            Some(value) => json_value(value, options, &mut line),
+            None => line.push_str("null"),
+        }
+    }
+
+    line.push_str("}\n");
+
+    line
+}
+
+fn json_value(value: &ArgValue, options: &ExportOptions, out: &mut String) {
+    match value {
+        ArgValue::Null => out.push_str("null"),
+        ArgValue::Bool(v) => out.push_str(if *v { "true" } else { "false" }),
+        ArgValue::I8(_)
+        | ArgValue::I16(_)
+        | ArgValue::I32(_)
+        | ArgValue::I64(_)
+        | ArgValue::U8(_)
+        | ArgValue::U16(_)
+        | ArgValue::U32(_)
+        | ArgValue::U64(_)
+        | ArgValue::Decimal(_) => {
+            let _ = write!(out, "{}", value);
+        }
+        ArgValue::F32(v) if v.is_finite() => {
+            let _ = write!(out, "{}", v);
+        }
+        ArgValue::F64(v) if v.is_finite() => {
+            let _ = write!(out, "{}", v);
+        }
+        ArgValue::F32(_) | ArgValue::F64(_) => out.push_str("null"),
+        #[cfg(feature = "json")]
+        ArgValue::Json(v) => {
+            let _ = write!(out, "{}", v);
+        }
+        ArgValue::Array(_, values) | ArgValue::Composite(values) => {
+            out.push('[');
+
+            for (i, value) in values.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+
+                json_value(value, options, out);
+            }
+
+            out.push(']');
+        }
+        value => json_string(&text(&Some(value.clone()), options), out),
+    }
+}
+
+fn json_string(value: &str, out: &mut String) {
+    out.push('"');
+
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+
+    out.push('"');
+}
+
+/// Escape characters that would break the table layout.
+fn table_cell(value: String, format: ExportFormat) -> String {
+    let value = value
+        .replace('\r', "\\r")
+        .replace('\n', "\\n")
+        .replace('\t', "\\t");
+
+    match format {
+        ExportFormat::Markdown => value.replace('|', "\\|"),
+        _ => value,
+    }
+}
+
+fn render_table(columns: &[Column], rows: Vec<Vec<String>>, format: ExportFormat) -> String {
+    let header = columns
+        .iter()
+        .map(|c| table_cell(c.column_name.clone(), format))
+        .collect::<Vec<_>>();
+
+    let mut widths = header.iter().map(|h| h.chars().count()).collect::<Vec<_>>();
+
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    if format == ExportFormat::Markdown {
+        // Markdown separator needs at least three dashes.
+        widths.iter_mut().for_each(|w| *w = (*w).max(3));
+    }
+
+    let line = |cells: &[String], out: &mut String| {
+        out.push('|');
+
+        for (cell, width) in cells.iter().zip(&widths) {
+            let _ = write!(out, " {:width$} |", cell, width = width);
+        }
+
+        out.push('\n');
+    };
+
+    let border = |out: &mut String| {
+        out.push('+');
+
+        for width in &widths {
+            let _ = write!(out, "{}+", "-".repeat(width + 2));
+        }
+
+        out.push('\n');
+    };
+
+    let mut out = String::new();
+
+    if format == ExportFormat::Markdown {
+        line(&header, &mut out);
+
+        let separator = widths.iter().map(|w| "-".repeat(*w)).collect::<Vec<_>>();
+
+        line(&separator, &mut out);
+
+        for row in &rows {
+            line(row, &mut out);
+        }
+    } else {
+        border(&mut out);
+        line(&header, &mut out);
+        border(&mut out);
+
+        for row in &rows {
+            line(row, &mut out);
+        }
+
+        border(&mut out);
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        driver::{
+            mock::{self, MockTable},
+            ArgValue,
+        },
+        BytesFormat, ExportFormat, ExportOptions, Preparable,
+    };
+
+    async fn export(options: ExportOptions) -> String {
+        let mut db = mock::open(MockTable::new(
+            &["id", "name", "data"],
+            vec![
+                vec![
+                    ArgValue::I64(1),
+                    ArgValue::String("a,\"b\"".to_owned()),
+                    ArgValue::Bytes(b"hi!".to_vec()),
+                ],
+                vec![ArgValue::I64(20), ArgValue::Null, ArgValue::Null],
+            ],
+        ));
+
+        let mut stmt = db.prepare("SELECT").await.unwrap();
+        let mut rows = stmt.query(vec![]).await.unwrap();
+
+        let mut out = vec![];
+
+        assert_eq!(rows.export(&mut out, &options).await.unwrap(), 2);
+
+        String::from_utf8(out).unwrap()
+    }
+
+    #[async_std::test]
+    async fn test_export() {
+        assert_eq!(
+            export(ExportFormat::Csv.into()).await,
+            "id,name,data\n1,\"a,\"\"b\"\"\",686921\n20,,\n"
+        );
+
+        assert_eq!(
+            export(ExportOptions {
+                format: ExportFormat::JsonLines,
+                bytes: BytesFormat::Base64,
+                ..Default::default()
+            })
+            .await,
+            "{\"id\":1,\"name\":\"a,\\\"b\\\"\",\"data\":\"aGkh\"}\n\
+             {\"id\":20,\"name\":null,\"data\":null}\n"
+        );
+
+        assert_eq!(
+            export(ExportOptions {
+                format: ExportFormat::Markdown,
+                null: "NULL".to_owned(),
+                bytes: BytesFormat::Length,
+                ..Default::default()
+            })
+            .await,
+            "| id  | name  | data      |\n\
+             | --- | ----- | --------- |\n\
+             | 1   | a,\"b\" | <3 bytes> |\n\
+             | 20  | NULL  | NULL      |\n"
+        );
+
+        assert_eq!(
+            export(ExportFormat::Table.into()).await,
+            "+----+-------+--------+\n\
+             | id | name  | data   |\n\
+             +----+-------+--------+\n\
+             | 1  | a,\"b\" | 686921 |\n\
+             | 20 |       |        |\n\
+             +----+-------+--------+\n"
+        );
+    }
+}
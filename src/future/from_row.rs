@@ -0,0 +1,91 @@
+use anyhow::Result;
+
+use super::{ConnectionPool, Rows};
+
+/// Create a value from the current row of [`Rows`].
+///
+/// Available as `#[derive(FromRow)]` on feature *derive*, the derived
+/// implementation reads each field with [`Rows::get_as`] by column name.
+#[async_trait::async_trait]
+pub trait FromRow: Sized {
+    async fn from_row<DB>(rows: &mut Rows<DB>) -> Result<Self>
+    where
+        DB: ConnectionPool + Sync + Send;
+}
+
+#[cfg(test)]
+mod tests {
+    use rdbc_derive::{FromRow, ToArguments};
+
+    use crate::{
+        driver::{
+            mock::{self, MockTable},
+            ArgValue, Argument, ToArguments,
+        },
+        Preparable,
+    };
+
+    #[derive(FromRow, ToArguments, Debug, PartialEq)]
+    struct Audit {
+        created_by: String,
+    }
+
+    #[derive(FromRow, ToArguments, Debug, PartialEq)]
+    struct User {
+        id: i64,
+        #[rdbc(rename = "user_name")]
+        name: String,
+        email: Option<String>,
+        #[rdbc(default)]
+        age: i32,
+        #[rdbc(skip)]
+        cached: Vec<u8>,
+        #[rdbc(flatten)]
+        audit: Audit,
+    }
+
+    #[async_std::test]
+    async fn test_derive_from_row() {
+        let mut db = mock::open(MockTable::new(
+            &["id", "user_name", "email", "created_by"],
+            vec![vec![
+                ArgValue::I64(1),
+                ArgValue::String("alice".to_owned()),
+                ArgValue::Null,
+                ArgValue::String("admin".to_owned()),
+            ]],
+        ));
+
+        let mut stmt = db.prepare("SELECT * FROM user").await.unwrap();
+        let mut rows = stmt.query(vec![]).await.unwrap();
+
+        assert!(rows.next().await.unwrap());
+
+        let user: User = super::FromRow::from_row(&mut rows).await.unwrap();
+
+        assert_eq!(
+            user,
+            User {
+                id: 1,
+                name: "alice".to_owned(),
+                email: None,
+                age: 0,
+                cached: vec![],
+                audit: Audit {
+                    created_by: "admin".to_owned()
+                }
+            }
+        );
+
+        assert_eq!(
+            user.to_arguments(),
+            vec![
+                Argument::new("id", 1i64),
+                Argument::new("user_name", "alice"),
+                Argument::new("email", ArgValue::Null),
+                Argument::new("age", 0i32),
+                Argument::new("created_by", "admin"),
+            ]
+        );
+    }
+}
@@ -21,4 +21,19 @@ pub use tx::*;
 mod rows;
 pub use rows::*;
 
+mod from_row;
+pub use from_row::*;
+
+mod row;
+pub use row::*;
+
+mod stream;
+pub use stream::*;
+
+mod reader;
+pub use reader::*;
+
+mod export;
+pub use export::*;
+
 mod driver;
@@ -1,4 +1,4 @@
-use super::{stmt::Statement, ConnectionPool};
+use super::{stmt::Statement, Argument, ConnectionPool, FromArgValue, FromRow};
 
 /// The trait to create a prepared statment for later queries or executions.
 #[async_trait::async_trait]
@@ -11,4 +11,52 @@ pub trait Preparable {
     fn driver_name(&self) -> &str;
 
     fn conn_str(&self) -> &str;
+
+    /// Prepare `query` and call [`Statement::fetch_all`].
+    async fn fetch_all<S, T>(&mut self, query: S, args: Vec<Argument>) -> anyhow::Result<Vec<T>>
+    where
+        S: Into<String> + Send,
+        T: FromRow + Send,
+        Self: Send,
+        Self::DB: Clone,
+    {
+        self.prepare(query).await?.fetch_all(args).await
+    }
+
+    /// Prepare `query` and call [`Statement::fetch_optional`].
+    async fn fetch_optional<S, T>(
+        &mut self,
+        query: S,
+        args: Vec<Argument>,
+    ) -> anyhow::Result<Option<T>>
+    where
+        S: Into<String> + Send,
+        T: FromRow + Send,
+        Self: Send,
+        Self::DB: Clone,
+    {
+        self.prepare(query).await?.fetch_optional(args).await
+    }
+
+    /// Prepare `query` and call [`Statement::fetch_one`].
+    async fn fetch_one<S, T>(&mut self, query: S, args: Vec<Argument>) -> anyhow::Result<T>
+    where
+        S: Into<String> + Send,
+        T: FromRow + Send,
+        Self: Send,
+        Self::DB: Clone,
+    {
+        self.prepare(query).await?.fetch_one(args).await
+    }
+
+    /// Prepare `query` and call [`Statement::fetch_scalar`].
+    async fn fetch_scalar<S, T>(&mut self, query: S, args: Vec<Argument>) -> anyhow::Result<T>
+    where
+        S: Into<String> + Send,
+        T: FromArgValue + Send,
+        Self: Send,
+        Self::DB: Clone,
+    {
+        self.prepare(query).await?.fetch_scalar(args).await
+    }
 }
@@ -0,0 +1,230 @@
+use std::{
+    future::Future,
+    io,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use anyhow::Result;
+use futures::io::AsyncRead;
+
+use crate::driver::{self, callback::BoxedCallback, RDBCError};
+
+use super::{driver::AsyncDriver, ConnectionPool, Rows};
+
+type ArgName = driver::ArgName;
+type ArgValue = driver::ArgValue;
+
+/// Default number of bytes requested by one [`driver::Rows::read_chunk`].
+pub const DEFAULT_CHUNK_SIZE: usize = 64 * 1024;
+
+/// [`AsyncRead`] over a BLOB/TEXT column of the current row.
+///
+/// Created by [`Rows::reader`]. The value is read in chunks with
+/// [`driver::Rows::read_chunk`], or as a whole with [`Rows::get`] if the
+/// driver doesn't support chunked reads. A `NULL` value reads as empty.
+pub struct ColumnReader<DB>
+where
+    DB: ConnectionPool + Sync + Send,
+{
+    rows: Rows<DB>,
+    name: ArgName,
+    offset: u64,
+    chunk_size: usize,
+    chunked: bool,
+    pending: Option<AsyncDriver<Option<Vec<u8>>>>,
+    buffer: Vec<u8>,
+    buffer_offset: usize,
+    eof: bool,
+}
+
+impl<DB> ColumnReader<DB>
+where
+    DB: ConnectionPool + Sync + Send,
+{
+    pub(crate) fn new(rows: Rows<DB>, name: ArgName) -> Self {
+        Self {
+            rows,
+            name,
+            offset: 0,
+            chunk_size: DEFAULT_CHUNK_SIZE,
+            chunked: true,
+            pending: None,
+            buffer: vec![],
+            buffer_offset: 0,
+            eof: false,
+        }
+    }
+
+    /// Set the number of bytes requested from the driver per chunk.
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = chunk_size.max(1);
+        self
+    }
+
+    fn start_read(&mut self) -> AsyncDriver<Option<Vec<u8>>> {
+        let async_driver = AsyncDriver::new();
+        let callback = async_driver.callback();
+        let name = self.name.clone();
+
+        let result = if self.chunked {
+            let (offset, len) = (self.offset, self.chunk_size);
+
+            self.rows
+                .with_driver(|rows| rows.read_chunk(name, offset, len, callback))
+        } else {
+            let callback = BoxedCallback::new(move |value: Result<Option<ArgValue>>| {
+                callback.invoke(value.and_then(|value| match value {
+                    Some(ArgValue::Bytes(bytes)) => Ok(Some(bytes)),
+                    Some(ArgValue::String(s)) => Ok(Some(s.into_bytes())),
+                    Some(ArgValue::Null) | None => Ok(None),
+                    Some(value) => {
+                        Err(RDBCError::ConvertError(value.to_string(), "Vec<u8>".to_owned()).into())
+                    }
+                }));
+            });
+
+            self.rows
+                .with_driver(|rows| rows.get(name, driver::ColumnType::Bytes, callback))
+        };
+
+        if let Err(err) = result {
+            async_driver.callback().invoke(Err(err));
+        }
+
+        async_driver
+    }
+}
+
+impl<DB> AsyncRead for ColumnReader<DB>
+where
+    DB: ConnectionPool + Sync + Send,
+{
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+
+        loop {
+            if this.buffer_offset < this.buffer.len() {
+                let len = buf.len().min(this.buffer.len() - this.buffer_offset);
+
+                buf[..len]
+                    .copy_from_slice(&this.buffer[this.buffer_offset..this.buffer_offset + len]);
+
+                this.buffer_offset += len;
+
+                return Poll::Ready(Ok(len));
+            }
+
+            if this.eof || buf.is_empty() {
+                return Poll::Ready(Ok(0));
+            }
+
+            if this.pending.is_none() {
+                this.pending = Some(this.start_read());
+            }
+
+            let result = match Pin::new(this.pending.as_mut().unwrap()).poll(cx) {
+                Poll::Ready(result) => result,
+                Poll::Pending => return Poll::Pending,
+            };
+
+            this.pending = None;
+
+            match result {
+                Ok(Some(chunk)) if !chunk.is_empty() => {
+                    this.offset += chunk.len() as u64;
+                    this.buffer = chunk;
+                    this.buffer_offset = 0;
+                    // Without chunked reads the whole value is fetched at once.
+                    this.eof = !this.chunked;
+                }
+                Ok(_) => this.eof = true,
+                Err(err)
+                    if this.chunked
+                        && matches!(
+                            err.downcast_ref::<RDBCError>(),
+                            Some(RDBCError::Unsupported(_))
+                        ) =>
+                {
+                    this.chunked = false;
+                }
+                Err(err) => return Poll::Ready(Err(io::Error::other(err))),
+            }
+        }
+    }
+}
+
+impl<DB> Rows<DB>
+where
+    DB: ConnectionPool + Sync + Send,
+{
+    /// Returns [`AsyncRead`] over BLOB/TEXT column `name` of the current row.
+    ///
+    /// The reader must be consumed before moving to the next row.
+    pub fn reader<N>(&self, name: N) -> ColumnReader<DB>
+    where
+        N: Into<ArgName>,
+    {
+        ColumnReader::new(self.clone(), name.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::AsyncReadExt;
+
+    use crate::{
+        driver::{
+            mock::{self, MockTable},
+            ArgValue,
+        },
+        Preparable,
+    };
+
+    async fn read_blob(read_chunk: bool) -> Vec<Vec<u8>> {
+        let blob: Vec<u8> = (0..=255).collect();
+
+        let mut table = MockTable::new(
+            &["id", "data"],
+            vec![
+                vec![ArgValue::I64(1), ArgValue::Bytes(blob)],
+                vec![ArgValue::I64(2), ArgValue::Null],
+            ],
+        );
+
+        table.read_chunk = read_chunk;
+
+        let mut db = mock::open(table);
+        let mut stmt = db.prepare("SELECT id, data FROM t").await.unwrap();
+        let mut rows = stmt.query(vec![]).await.unwrap();
+
+        let mut result = vec![];
+
+        while rows.next().await.unwrap() {
+            let mut data = vec![];
+
+            rows.reader("data")
+                .with_chunk_size(10)
+                .read_to_end(&mut data)
+                .await
+                .unwrap();
+
+            result.push(data);
+        }
+
+        result
+    }
+
+    #[async_std::test]
+    async fn test_reader() {
+        let blobs = read_blob(true).await;
+
+        assert_eq!(blobs, read_blob(false).await);
+        assert_eq!(blobs[0], (0..=255).collect::<Vec<u8>>());
+        assert!(blobs[1].is_empty());
+    }
+}
@@ -0,0 +1,213 @@
+use std::{collections::HashMap, sync::Arc};
+
+use anyhow::Result;
+
+use crate::driver::{self, FromArgValue, RDBCError};
+
+use super::{ConnectionPool, FromRow, Rows};
+
+type Column = driver::Column;
+type ArgValue = driver::ArgValue;
+type ArgName = driver::ArgName;
+
+/// Owned snapshot of one row of [`Rows`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Row {
+    columns: Arc<Vec<Column>>,
+    values: Vec<Option<ArgValue>>,
+}
+
+impl Row {
+    pub fn new(columns: Arc<Vec<Column>>, values: Vec<Option<ArgValue>>) -> Self {
+        Self { columns, values }
+    }
+
+    /// Result set columns, shared by all rows of the same result set.
+    pub fn columns(&self) -> &[Column] {
+        &self.columns
+    }
+
+    /// Column values in column order.
+    pub fn values(&self) -> &[Option<ArgValue>] {
+        &self.values
+    }
+
+    pub fn into_values(self) -> Vec<Option<ArgValue>> {
+        self.values
+    }
+
+    /// Returns the number of columns.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns the position of column `name`, the first one if several columns
+    /// have the same name.
+    pub fn index_of(&self, name: &str) -> Option<usize> {
+        self.columns.iter().position(|c| c.column_name == name)
+    }
+
+    /// Get value by [`Column::column_index`] or column name, [`None`] means
+    /// SQL `NULL`.
+    pub fn get<N>(&self, name: N) -> Result<Option<&ArgValue>, RDBCError>
+    where
+        N: Into<ArgName>,
+    {
+        let index = match name.into() {
+            ArgName::Offset(index) => self
+                .columns
+                .iter()
+                .position(|c| c.column_index as usize == index)
+                .filter(|&position| position < self.values.len())
+                .ok_or(RDBCError::OutOfRange(index as u64))?,
+            ArgName::String(name) => self.index_of(&name).ok_or(RDBCError::UnknownColumn(name))?,
+        };
+
+        Ok(match &self.values[index] {
+            Some(ArgValue::Null) | None => None,
+            Some(value) => Some(value),
+        })
+    }
+
+    /// Get value by [`Column::column_index`] or column name and convert it into `T`.
+    pub fn get_as<T, N>(&self, name: N) -> Result<T, RDBCError>
+    where
+        T: FromArgValue,
+        N: Into<ArgName>,
+    {
+        T::from_arg_value(self.get(name)?.cloned())
+    }
+
+    /// Iterate `(column, value)` pairs in column order.
+    pub fn iter(&self) -> impl Iterator<Item = (&Column, Option<&ArgValue>)> {
+        self.columns
+            .iter()
+            .zip(self.values.iter().map(Option::as_ref))
+    }
+
+    /// Dynamic view of the row keyed by column name, later columns win if
+    /// several columns have the same name.
+    pub fn to_map(&self) -> HashMap<String, Option<ArgValue>> {
+        self.iter()
+            .map(|(column, value)| (column.column_name.clone(), value.cloned()))
+            .collect()
+    }
+}
+
+/// Read every column of the current row as its natural type, see
+/// [`Rows::get_value`].
+#[async_trait::async_trait]
+impl FromRow for Row {
+    async fn from_row<DB>(rows: &mut Rows<DB>) -> Result<Self>
+    where
+        DB: ConnectionPool + Sync + Send,
+    {
+        let columns = rows.shared_columns().await?;
+
+        let mut values = Vec::with_capacity(columns.len());
+
+        for column in columns.iter() {
+            let column_type = rows.column_type(column);
+
+            values.push(
+                rows.get(ArgName::Offset(column.column_index as usize), column_type)
+                    .await?,
+            );
+        }
+
+        Ok(Self { columns, values })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        driver::{
+            mock::{self, MockTable},
+            ArgValue, RDBCError,
+        },
+        Preparable,
+    };
+
+    async fn read_rows(next_row: bool) -> Vec<super::Row> {
+        let mut table = MockTable::new(
+            &["id", "name"],
+            vec![
+                vec![ArgValue::I64(1), ArgValue::String("a".to_owned())],
+                vec![ArgValue::I64(2), ArgValue::Null],
+            ],
+        );
+
+        table.next_row = next_row;
+
+        let mut db = mock::open(table);
+        let mut stmt = db.prepare("SELECT id, name FROM t").await.unwrap();
+        let mut rows = stmt.query(vec![]).await.unwrap();
+
+        let mut result = vec![];
+
+        while let Some(row) = rows.next_row().await.unwrap() {
+            result.push(row);
+        }
+
+        result
+    }
+
+    #[async_std::test]
+    async fn test_next_row() {
+        let rows = read_rows(true).await;
+
+        assert_eq!(rows, read_rows(false).await);
+        assert_eq!(rows.len(), 2);
+
+        assert_eq!(rows[0].len(), 2);
+        assert_eq!(rows[0].get(0).unwrap(), Some(&ArgValue::I64(1)));
+        assert_eq!(rows[0].get_as::<String, _>("name").unwrap(), "a");
+        assert_eq!(rows[1].get("name").unwrap(), None);
+        assert_eq!(rows[1].get_as::<Option<String>, _>(1).unwrap(), None);
+        assert!(matches!(rows[1].get(2), Err(RDBCError::OutOfRange(2))));
+        assert!(matches!(
+            rows[1].get("email"),
+            Err(RDBCError::UnknownColumn(_))
+        ));
+
+        let map = rows[0].to_map();
+
+        assert_eq!(map["id"], Some(ArgValue::I64(1)));
+        assert_eq!(map["name"], Some(ArgValue::String("a".to_owned())));
+    }
+
+    #[async_std::test]
+    async fn test_column_index() {
+        let mut table = MockTable::new(
+            &["id", "name"],
+            vec![vec![ArgValue::I64(1), ArgValue::String("a".to_owned())]],
+        );
+
+        // Columns numbered from 1 by the driver.
+        for column in &mut table.columns {
+            column.column_index += 1;
+        }
+
+        let mut db = mock::open(table);
+
+        let id: i64 = db.fetch_scalar("SELECT", vec![]).await.unwrap();
+        assert_eq!(id, 1);
+
+        let mut stmt = db.prepare("SELECT id, name FROM t").await.unwrap();
+        let mut rows = stmt.query(vec![]).await.unwrap();
+
+        assert!(rows.next().await.unwrap());
+        assert_eq!(rows.get_value(1).await.unwrap(), Some(ArgValue::I64(1)));
+
+        let row: super::Row = crate::FromRow::from_row(&mut rows).await.unwrap();
+
+        assert_eq!(row.get(1).unwrap(), Some(&ArgValue::I64(1)));
+        assert_eq!(row.get_as::<String, _>(2).unwrap(), "a");
+        assert!(matches!(row.get(0), Err(RDBCError::OutOfRange(0))));
+    }
+}
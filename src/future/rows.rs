@@ -1,11 +1,11 @@
 //! Asynchronous wrapper type for [`crate::driver::Rows`]
 
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, Mutex, Weak};
 
-use crate::driver;
+use crate::driver::{self, callback::BoxedCallback, FromArgValue, RDBCError};
 use anyhow::Result;
 
-use super::{driver::AsyncDriver, ConnectionPool, Statement};
+use super::{driver::AsyncDriver, ConnectionPool, FromRow, Row, Statement};
 
 type Column = driver::Column;
 type ArgValue = driver::ArgValue;
@@ -16,12 +16,67 @@ struct Inner<DB>
 where
     DB: ConnectionPool + Sync + Send,
 {
-    rows: Box<dyn driver::Rows>,
-    stmt: Statement<DB>,
+    rows: Option<Box<dyn driver::Rows>>,
+    stmt: Option<Statement<DB>>,
+    columns: Option<Arc<Vec<Column>>>,
+    types: Arc<driver::TypeRegistry>,
+    next_row_unsupported: bool,
+    exhausted: bool,
+}
+
+/// Implement [`Drop`] trait to tell the driver unread rows are abandoned
+/// before the statement reference is released.
+impl<DB> Drop for Inner<DB>
+where
+    DB: ConnectionPool + Sync + Send,
+{
+    fn drop(&mut self) {
+        if let Some(mut rows) = self.rows.take() {
+            if !self.exhausted {
+                rows.close(BoxedCallback::new(|_| {}));
+            }
+        }
+    }
+}
+
+/// Weak reference to [`Rows`] kept by the [`Statement`] that created them, so
+/// [`Statement::close`] can close the driver rows before the connection is
+/// returned to the pool.
+pub(crate) struct RowsRef<DB>(Weak<Mutex<Inner<DB>>>)
+where
+    DB: ConnectionPool + Sync + Send;
+
+impl<DB> RowsRef<DB>
+where
+    DB: ConnectionPool + Sync + Send,
+{
+    /// Returns false if every clone of the rows was dropped.
+    pub(crate) fn is_alive(&self) -> bool {
+        self.0.strong_count() > 0
+    }
+
+    /// Close the driver rows if they are still alive, later calls on them
+    /// return [`RDBCError::Closed`].
+    pub(crate) fn close(&self) {
+        if let Some(inner) = self.0.upgrade() {
+            let (rows, stmt) = {
+                let mut inner = inner.lock().unwrap();
+
+                let rows = inner.rows.take().filter(|_| !inner.exhausted);
+
+                (rows, inner.stmt.take())
+            };
+
+            if let Some(mut rows) = rows {
+                rows.close(BoxedCallback::new(|_| {}));
+            }
+
+            drop(stmt);
+        }
+    }
 }
 
 /// Asynchronous wrapper type for [`crate::driver::Rows`]
-#[derive(Clone)]
 pub struct Rows<DB>
 where
     DB: ConnectionPool + Sync + Send,
@@ -29,38 +84,178 @@ where
     inner: Arc<Mutex<Inner<DB>>>,
 }
 
+impl<DB> Clone for Rows<DB>
+where
+    DB: ConnectionPool + Sync + Send,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
 impl<DB> Rows<DB>
 where
     DB: ConnectionPool + Sync + Send,
 {
-    pub(crate) fn new(rows: Box<dyn driver::Rows>, stmt: Statement<DB>) -> Self {
+    pub(crate) fn new(
+        rows: Box<dyn driver::Rows>,
+        stmt: Statement<DB>,
+        types: Arc<driver::TypeRegistry>,
+    ) -> Self {
         Self {
-            inner: Arc::new(Mutex::new(Inner { rows, stmt })),
+            inner: Arc::new(Mutex::new(Inner {
+                rows: Some(rows),
+                stmt: Some(stmt),
+                columns: None,
+                types,
+                next_row_unsupported: false,
+                exhausted: false,
+            })),
         }
     }
 
+    pub(crate) fn downgrade(&self) -> RowsRef<DB> {
+        RowsRef(Arc::downgrade(&self.inner))
+    }
+
+    /// Returns result set columns, the driver is asked once and the result cached.
     pub async fn colunms(&mut self) -> Result<Vec<Column>> {
+        Ok(self.shared_columns().await?.as_ref().clone())
+    }
+
+    pub(crate) async fn shared_columns(&mut self) -> Result<Arc<Vec<Column>>> {
+        if let Some(columns) = &self.inner.lock().unwrap().columns {
+            return Ok(columns.clone());
+        }
+
         let async_driver = AsyncDriver::new();
 
-        {
-            let mut inner = self.inner.lock().unwrap();
+        self.with_driver(|rows| rows.colunms(async_driver.callback()))?;
 
-            inner.rows.colunms(async_driver.callback());
-        }
+        let columns = Arc::new(async_driver.await?);
 
-        async_driver.await
+        self.inner.lock().unwrap().columns = Some(columns.clone());
+
+        Ok(columns)
+    }
+
+    /// Returns true if result set has column named `name`.
+    pub async fn contains_column(&mut self, name: &str) -> Result<bool> {
+        Ok(self
+            .shared_columns()
+            .await?
+            .iter()
+            .any(|column| column.column_name == name))
     }
 
     pub async fn next(&mut self) -> Result<bool> {
         let async_driver = AsyncDriver::new();
 
-        {
-            let mut inner = self.inner.lock().unwrap();
+        self.with_driver(|rows| rows.next(async_driver.callback()))?;
+
+        let has_next = async_driver.await?;
+
+        self.inner.lock().unwrap().exhausted = !has_next;
+
+        Ok(has_next)
+    }
+
+    /// Move to the next row and read it as owned [`Row`], returns [`None`] if
+    /// there are no more rows.
+    ///
+    /// The row is fetched with one [`driver::Rows::next_row`] callback, or with
+    /// [`Rows::next`] plus one [`Rows::get`] per column if the driver doesn't
+    /// support it.
+    pub async fn next_row(&mut self) -> Result<Option<Row>> {
+        let columns = self.shared_columns().await?;
+
+        if !self.inner.lock().unwrap().next_row_unsupported {
+            let async_driver = AsyncDriver::new();
+
+            self.with_driver(|rows| rows.next_row(async_driver.callback()))?;
+
+            match async_driver.await {
+                Ok(values) => {
+                    self.inner.lock().unwrap().exhausted = values.is_none();
 
-            inner.rows.next(async_driver.callback());
+                    return Ok(values.map(|values| Row::new(columns, values)));
+                }
+                Err(err)
+                    if matches!(
+                        err.downcast_ref::<RDBCError>(),
+                        Some(RDBCError::Unsupported(_))
+                    ) =>
+                {
+                    self.inner.lock().unwrap().next_row_unsupported = true;
+                }
+                Err(err) => return Err(err),
+            }
         }
 
-        async_driver.await
+        if self.next().await? {
+            Ok(Some(Row::from_row(self).await?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Call `f` with the driver rows while holding the rows lock.
+    ///
+    /// Returns [`RDBCError::Closed`] if the rows were closed.
+    pub(crate) fn with_driver<R>(&self, f: impl FnOnce(&mut dyn driver::Rows) -> R) -> Result<R> {
+        match self.inner.lock().unwrap().rows.as_mut() {
+            Some(rows) => Ok(f(rows.as_mut())),
+            None => Err(RDBCError::Closed("rows".to_owned()).into()),
+        }
+    }
+
+    /// Close the result set, unread rows are discarded by the driver.
+    ///
+    /// The reference to the [`Statement`] is released, so the connection
+    /// returns to the pool as soon as the statement itself is dropped. Later
+    /// calls on any clone of these rows return [`RDBCError::Closed`].
+    pub async fn close(&mut self) -> Result<()> {
+        let (rows, stmt) = {
+            let mut inner = self.inner.lock().unwrap();
+
+            (inner.rows.take(), inner.stmt.take())
+        };
+
+        let mut rows = match rows {
+            Some(rows) => rows,
+            None => return Ok(()),
+        };
+
+        let async_driver = AsyncDriver::new();
+
+        rows.close(async_driver.callback());
+
+        let result = async_driver.await;
+
+        drop(rows);
+        drop(stmt);
+
+        result
+    }
+
+    /// Move to the next result set, returns false if there are no more result sets.
+    ///
+    /// Cached column metadata is dropped, so [`Rows::colunms`] returns the
+    /// columns of the new result set.
+    pub async fn next_result_set(&mut self) -> Result<bool> {
+        let async_driver = AsyncDriver::new();
+
+        self.inner.lock().unwrap().columns = None;
+
+        self.with_driver(|rows| rows.next_result_set(async_driver.callback()))?;
+
+        let has_next = async_driver.await?;
+
+        self.inner.lock().unwrap().exhausted = !has_next;
+
+        Ok(has_next)
     }
 
     pub async fn get<N>(
@@ -73,14 +268,244 @@ where
     {
         let async_driver = AsyncDriver::new();
 
-        {
-            let mut inner = self.inner.lock().unwrap();
+        self.with_driver(|rows| rows.get(name.into(), column_type, async_driver.callback()))?;
+
+        async_driver.await
+    }
+
+    /// Returns the type mapping used to resolve [`Rows::column_type`].
+    pub fn type_registry(&self) -> Arc<driver::TypeRegistry> {
+        self.inner.lock().unwrap().types.clone()
+    }
+
+    /// Returns the natural type of `column`, see [`driver::TypeRegistry::column_type`].
+    pub fn column_type(&self, column: &Column) -> driver::ColumnType {
+        self.inner.lock().unwrap().types.column_type(column)
+    }
+
+    /// Get current row value by [`Column::column_index`] or column name, read
+    /// as the natural type of the column.
+    ///
+    /// The type is [`Column::column_type`] if the driver reports it, or
+    /// [`Column::column_decltype`] mapped by the driver [`driver::TypeRegistry`].
+    pub async fn get_value<N>(&mut self, name: N) -> Result<Option<ArgValue>>
+    where
+        N: Into<ArgName>,
+    {
+        let columns = self.shared_columns().await?;
+
+        let column = match name.into() {
+            ArgName::Offset(index) => columns
+                .iter()
+                .find(|c| c.column_index as usize == index)
+                .ok_or(RDBCError::OutOfRange(index as u64))?,
+            ArgName::String(name) => columns
+                .iter()
+                .find(|c| c.column_name == name)
+                .ok_or(RDBCError::UnknownColumn(name))?,
+        };
+
+        let column_type = self.column_type(column);
+
+        self.get(ArgName::Offset(column.column_index as usize), column_type)
+            .await
+    }
+
+    /// Get current row value by arg name and convert it into `T`.
+    ///
+    /// The [`driver::ColumnType`] passed to the driver is [`FromArgValue::column_type`].
+    pub async fn get_as<T, N>(&mut self, name: N) -> Result<T>
+    where
+        T: FromArgValue,
+        N: Into<ArgName>,
+    {
+        let value = self.get(name, T::column_type()).await?;
+
+        Ok(T::from_arg_value(value)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::Ordering;
+
+    use crate::{
+        driver::{
+            mock::{self, MockTable},
+            ArgValue, Column, ColumnType, RDBCError,
+        },
+        Preparable,
+    };
+
+    fn is_closed(err: anyhow::Error) -> bool {
+        matches!(err.downcast_ref(), Some(RDBCError::Closed(_)))
+    }
 
-            inner
-                .rows
-                .get(name.into(), column_type, async_driver.callback());
+    #[async_std::test]
+    async fn test_next_result_set() {
+        let mut table = MockTable::new(&["id"], vec![vec![ArgValue::I64(1)]]);
+
+        table.result_sets.push(MockTable::new(
+            &["name", "age"],
+            vec![
+                vec![ArgValue::String("a".to_owned()), ArgValue::I64(3)],
+                vec![ArgValue::String("b".to_owned()), ArgValue::I64(4)],
+            ],
+        ));
+
+        let mut db = mock::open(table);
+        let mut stmt = db.prepare("CALL proc()").await.unwrap();
+        let mut rows = stmt.query(vec![]).await.unwrap();
+
+        assert_eq!(rows.colunms().await.unwrap().len(), 1);
+        assert!(rows.next().await.unwrap());
+        assert_eq!(rows.get_as::<i64, _>("id").await.unwrap(), 1);
+
+        assert!(rows.next_result_set().await.unwrap());
+
+        let columns = rows.colunms().await.unwrap();
+
+        assert_eq!(columns.len(), 2);
+        assert_eq!(columns[0].column_name, "name");
+
+        let mut ages = vec![];
+
+        while let Some(row) = rows.next_row().await.unwrap() {
+            ages.push(row.get_as::<i64, _>("age").unwrap());
         }
 
-        async_driver.await
+        assert_eq!(ages, vec![3, 4]);
+
+        assert!(!rows.next_result_set().await.unwrap());
+    }
+
+    #[async_std::test]
+    async fn test_close() {
+        let table = MockTable::new(
+            &["id"],
+            vec![vec![ArgValue::I64(1)], vec![ArgValue::I64(2)]],
+        );
+        let rows_closed = table.rows_closed.clone();
+        let stmts_closed = table.stmts_closed.clone();
+
+        let mut db = mock::open(table);
+        let mut stmt = db.prepare("SELECT id FROM t").await.unwrap();
+
+        // Fully read rows are not closed on drop.
+        let mut rows = stmt.query(vec![]).await.unwrap();
+        while rows.next().await.unwrap() {}
+        drop(rows);
+        assert_eq!(rows_closed.load(Ordering::SeqCst), 0);
+
+        // Partially read rows are closed on drop.
+        let mut rows = stmt.query(vec![]).await.unwrap();
+        assert!(rows.next().await.unwrap());
+        drop(rows);
+        assert_eq!(rows_closed.load(Ordering::SeqCst), 1);
+
+        let mut rows = stmt.query(vec![]).await.unwrap();
+        let mut cloned = rows.clone();
+        rows.close().await.unwrap();
+        rows.close().await.unwrap();
+        assert_eq!(rows_closed.load(Ordering::SeqCst), 2);
+        assert!(is_closed(cloned.next().await.unwrap_err()));
+        drop(cloned);
+        assert_eq!(rows_closed.load(Ordering::SeqCst), 2);
+
+        // Closing the statement closes rows that are still alive.
+        let mut rows = stmt.query(vec![]).await.unwrap();
+        assert!(rows.next().await.unwrap());
+
+        stmt.close().await.unwrap();
+        assert_eq!(rows_closed.load(Ordering::SeqCst), 3);
+        assert_eq!(stmts_closed.load(Ordering::SeqCst), 1);
+        assert!(is_closed(rows.next().await.unwrap_err()));
+        assert!(is_closed(stmt.execute(vec![]).await.unwrap_err()));
+        assert!(is_closed(stmt.query(vec![]).await.err().unwrap()));
+
+        drop(rows);
+        drop(stmt);
+        assert_eq!(rows_closed.load(Ordering::SeqCst), 3);
+        assert_eq!(stmts_closed.load(Ordering::SeqCst), 1);
+
+        // Dropped statements are closed too.
+        let stmt = db.prepare("SELECT id FROM t").await.unwrap();
+        drop(stmt);
+        assert_eq!(stmts_closed.load(Ordering::SeqCst), 2);
+    }
+
+    #[async_std::test]
+    async fn test_get_value() {
+        let mut table = MockTable::new(
+            &["id", "price", "name"],
+            vec![vec![
+                ArgValue::I64(1),
+                ArgValue::Decimal("1.50".parse().unwrap()),
+                ArgValue::String("a".to_owned()),
+            ]],
+        );
+
+        table.columns[0].column_decltype = "INT(11)".to_owned();
+        table.columns[1].column_decltype = "NUMERIC(10,2)".to_owned();
+        table.columns[2].column_decltype = "VARCHAR(32)".to_owned();
+
+        let mut db = mock::open(table);
+        let mut stmt = db.prepare("SELECT").await.unwrap();
+        let mut rows = stmt.query(vec![]).await.unwrap();
+
+        assert!(rows.next().await.unwrap());
+
+        assert_eq!(rows.get_value("id").await.unwrap(), Some(ArgValue::I32(1)));
+        assert_eq!(
+            rows.get_value(1).await.unwrap(),
+            Some(ArgValue::Decimal("1.50".parse().unwrap()))
+        );
+        assert_eq!(
+            rows.get_value("name").await.unwrap(),
+            Some(ArgValue::String("a".to_owned()))
+        );
+        assert!(matches!(
+            rows.get_value("email")
+                .await
+                .unwrap_err()
+                .downcast_ref::<RDBCError>(),
+            Some(RDBCError::UnknownColumn(_))
+        ));
+    }
+
+    #[async_std::test]
+    async fn test_column_metadata() {
+        let mut table = MockTable::new(&["id", "price"], vec![]);
+
+        table.columns[0] = Column {
+            column_decltype: "BIGINT".to_owned(),
+            column_nullable: Some(false),
+            column_table: Some("orders".to_owned()),
+            column_schema: Some("shop".to_owned()),
+            column_auto_increment: Some(true),
+            column_primary_key: Some(true),
+            column_type: Some(ColumnType::I64),
+            ..table.columns[0].clone()
+        };
+        table.columns[1] = Column {
+            column_decltype: "DECIMAL(10,2)".to_owned(),
+            column_nullable: Some(true),
+            column_precision: Some(10),
+            column_scale: Some(2),
+            ..table.columns[1].clone()
+        };
+
+        let expected = table.columns.clone();
+
+        let mut db = mock::open(table);
+        let mut stmt = db.prepare("SELECT").await.unwrap();
+        let mut rows = stmt.query(vec![]).await.unwrap();
+
+        let columns = rows.colunms().await.unwrap();
+
+        assert_eq!(columns, expected);
+        assert_eq!(columns[0].column_primary_key, Some(true));
+        assert_eq!(columns[1].column_auto_increment, None);
+        assert_eq!(columns[1].column_scale, Some(2));
     }
 }
@@ -1,8 +1,8 @@
 use std::sync::{Arc, Mutex};
 
-use crate::driver;
+use crate::driver::{self, callback::BoxedCallback};
 
-use super::{driver::AsyncDriver, ConnectionPool, Rows};
+use super::{driver::AsyncDriver, rows::RowsRef, ConnectionPool, FromRow, Rows};
 
 use anyhow::Result;
 
@@ -24,6 +24,17 @@ pub type Column = driver::Column;
 /// Column type enum.
 pub type ColumnType = driver::ColumnType;
 
+/// Exact decimal value type.
+pub type Decimal = driver::Decimal;
+
+/// Query fetch size and cursor hints.
+pub type QueryOptions = driver::QueryOptions;
+
+/// Query cursor mode.
+pub type CursorMode = driver::CursorMode;
+
+pub use driver::{FromArgValue, ToArgValue, ToArguments};
+
 #[allow(dead_code)]
 struct Inner<DB>
 where
@@ -32,22 +43,53 @@ where
     db: Option<DB>,
     conn: Option<Box<dyn driver::Connection>>,
     stmt: Option<Box<dyn driver::Statement>>,
+    options: QueryOptions,
+    types: Arc<driver::TypeRegistry>,
+    rows: Vec<RowsRef<DB>>,
 }
-/// Implement [`Drop`] trait to return conn to [`super::ConnectionPool`]
+/// Implement [`Drop`] trait to close the driver statement and return conn to
+/// [`super::ConnectionPool`]
 impl<DB> Drop for Inner<DB>
 where
     DB: ConnectionPool + Sync + Send,
 {
     fn drop(&mut self) {
+        if let Some(mut stmt) = self.stmt.take() {
+            stmt.close(BoxedCallback::new(|_| {}));
+        }
+
         if let Some(conn) = self.conn.take() {
             if let Some(db) = self.db.take() {
-                drop(self.stmt.take().unwrap());
                 db.release_conn(conn);
             }
         }
     }
 }
 
+/// Driver statement taken by [`Statement::close`], dropped and its conn
+/// returned to [`super::ConnectionPool`] even if the close is cancelled.
+struct Closing<DB>
+where
+    DB: ConnectionPool + Sync + Send,
+{
+    db: Option<DB>,
+    conn: Option<Box<dyn driver::Connection>>,
+    stmt: Option<Box<dyn driver::Statement>>,
+}
+
+impl<DB> Drop for Closing<DB>
+where
+    DB: ConnectionPool + Sync + Send,
+{
+    fn drop(&mut self) {
+        drop(self.stmt.take());
+
+        if let (Some(conn), Some(db)) = (self.conn.take(), self.db.take()) {
+            db.release_conn(conn);
+        }
+    }
+}
+
 /// Asynchronous wrapper type for [`crate::driver::Statement`]
 #[allow(dead_code)]
 #[derive(Clone)]
@@ -67,25 +109,55 @@ where
         conn: Option<Box<dyn driver::Connection>>,
         stmt: Box<dyn driver::Statement>,
     ) -> Self {
+        let types = db
+            .as_ref()
+            .map(|db| db.type_registry())
+            .unwrap_or_else(driver::TypeRegistry::shared);
+
         Self {
             inner: Arc::new(Mutex::new(Inner {
                 db,
                 conn,
                 stmt: Some(stmt),
+                options: Default::default(),
+                types,
+                rows: vec![],
             })),
         }
     }
 
+    /// Set the column type mapping used by [`Rows::get_value`].
+    pub(crate) fn with_type_registry(self, types: Arc<driver::TypeRegistry>) -> Self {
+        self.inner.lock().unwrap().types = types;
+        self
+    }
+
+    /// Returns the column type mapping used by [`Rows::get_value`].
+    pub fn type_registry(&self) -> Arc<driver::TypeRegistry> {
+        self.inner.lock().unwrap().types.clone()
+    }
+
+    /// Call `f` with the driver statement and query options while holding the
+    /// statement lock.
+    ///
+    /// Returns [`driver::RDBCError::Closed`] if the statement was closed.
+    fn with_driver<R>(
+        &self,
+        f: impl FnOnce(&mut dyn driver::Statement, &QueryOptions) -> R,
+    ) -> Result<R> {
+        let mut inner = self.inner.lock().unwrap();
+        let inner = &mut *inner;
+
+        match inner.stmt.as_mut() {
+            Some(stmt) => Ok(f(stmt.as_mut(), &inner.options)),
+            None => Err(driver::RDBCError::Closed("statement".to_owned()).into()),
+        }
+    }
+
     pub async fn num_input(&self) -> Result<Option<usize>> {
         let async_driver = AsyncDriver::new();
 
-        self.inner
-            .lock()
-            .unwrap()
-            .stmt
-            .as_ref()
-            .unwrap()
-            .num_input(async_driver.callback());
+        self.with_driver(|stmt, _| stmt.num_input(async_driver.callback()))?;
 
         async_driver.await
     }
@@ -93,28 +165,257 @@ where
     pub async fn execute(&mut self, args: Vec<Argument>) -> Result<ExecResult> {
         let async_driver = AsyncDriver::new();
 
-        self.inner
-            .lock()
-            .unwrap()
-            .stmt
-            .as_mut()
-            .unwrap()
-            .execute(args, async_driver.callback());
+        self.with_driver(|stmt, _| stmt.execute(args, async_driver.callback()))?;
 
         async_driver.await
     }
 
+    /// Executes a query that may return rows, passing the [`QueryOptions`] set
+    /// on this statement to the driver.
     pub async fn query(&mut self, args: Vec<Argument>) -> Result<Rows<DB>> {
         let async_driver = AsyncDriver::new();
 
-        self.inner
-            .lock()
-            .unwrap()
-            .stmt
-            .as_mut()
+        self.with_driver(|stmt, options| {
+            stmt.query_with(args, options.clone(), async_driver.callback())
+        })?;
+
+        let rows = Rows::new(async_driver.await?, self.clone(), self.type_registry());
+
+        let mut inner = self.inner.lock().unwrap();
+
+        inner.rows.retain(|rows| rows.is_alive());
+        inner.rows.push(rows.downgrade());
+
+        Ok(rows)
+    }
+
+    /// Close the statement and return its connection to the pool immediately,
+    /// instead of waiting for every clone of the statement to be dropped.
+    ///
+    /// [`Rows`] created by this statement that are still alive are closed
+    /// first. Later calls on any clone of the statement or of those rows return
+    /// [`driver::RDBCError::Closed`].
+    pub async fn close(&mut self) -> Result<()> {
+        let (mut closing, rows) = {
+            let mut inner = self.inner.lock().unwrap();
+
+            let closing = Closing {
+                stmt: inner.stmt.take(),
+                conn: inner.conn.take(),
+                db: inner.db.take(),
+            };
+
+            (closing, std::mem::take(&mut inner.rows))
+        };
+
+        for rows in rows {
+            rows.close();
+        }
+
+        let stmt = match closing.stmt.as_mut() {
+            Some(stmt) => stmt,
+            None => return Ok(()),
+        };
+
+        let async_driver = AsyncDriver::new();
+
+        stmt.close(async_driver.callback());
+
+        async_driver.await
+    }
+
+    /// Set the number of rows the driver fetches per round trip for later queries.
+    pub fn set_fetch_size(&mut self, fetch_size: Option<usize>) {
+        self.inner.lock().unwrap().options.fetch_size = fetch_size;
+    }
+
+    /// Set the cursor mode for later queries, use [`CursorMode::Server`] to scan
+    /// result sets larger than memory.
+    pub fn set_cursor_mode(&mut self, cursor_mode: CursorMode) {
+        self.inner.lock().unwrap().options.cursor_mode = cursor_mode;
+    }
+
+    /// Returns the options passed to the driver by [`Statement::query`].
+    pub fn query_options(&self) -> QueryOptions {
+        self.inner.lock().unwrap().options.clone()
+    }
+
+    /// Query and read all rows as `T`.
+    pub async fn fetch_all<T>(&mut self, args: Vec<Argument>) -> Result<Vec<T>>
+    where
+        T: FromRow,
+    {
+        let mut rows = self.query(args).await?;
+
+        let mut values = vec![];
+
+        while rows.next().await? {
+            values.push(T::from_row(&mut rows).await?);
+        }
+
+        Ok(values)
+    }
+
+    /// Query and read at most one row as `T`.
+    ///
+    /// Returns [`driver::RDBCError::UnexpectRows`] if the query returns more than one row.
+    pub async fn fetch_optional<T>(&mut self, args: Vec<Argument>) -> Result<Option<T>>
+    where
+        T: FromRow,
+    {
+        let mut rows = self.query(args).await?;
+
+        if !rows.next().await? {
+            return Ok(None);
+        }
+
+        let value = T::from_row(&mut rows).await?;
+
+        if rows.next().await? {
+            return Err(driver::RDBCError::UnexpectRows.into());
+        }
+
+        Ok(Some(value))
+    }
+
+    /// Query and read exactly one row as `T`.
+    ///
+    /// Returns [`driver::RDBCError::UnexpectRows`] if the query doesn't return one row.
+    pub async fn fetch_one<T>(&mut self, args: Vec<Argument>) -> Result<T>
+    where
+        T: FromRow,
+    {
+        self.fetch_optional(args)
+            .await?
+            .ok_or_else(|| driver::RDBCError::UnexpectRows.into())
+    }
+
+    /// Query and read the first column of exactly one row as `T`.
+    ///
+    /// Returns [`driver::RDBCError::UnexpectRows`] if the query doesn't return one row.
+    pub async fn fetch_scalar<T>(&mut self, args: Vec<Argument>) -> Result<T>
+    where
+        T: FromArgValue,
+    {
+        let mut rows = self.query(args).await?;
+
+        if !rows.next().await? {
+            return Err(driver::RDBCError::UnexpectRows.into());
+        }
+
+        let index = match rows.colunms().await?.first() {
+            Some(column) => column.column_index as usize,
+            None => return Err(driver::RDBCError::OutOfRange(0).into()),
+        };
+
+        let value = rows.get_as(index).await?;
+
+        if rows.next().await? {
+            return Err(driver::RDBCError::UnexpectRows.into());
+        }
+
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        driver::{
+            mock::{self, MockTable},
+            ArgValue, RDBCError,
+        },
+        CursorMode, Database, Preparable, QueryOptions, Row,
+    };
+
+    fn open(rows: i64) -> Database {
+        mock::open(MockTable::new(
+            &["id", "name"],
+            (0..rows)
+                .map(|i| vec![ArgValue::I64(i), ArgValue::String(format!("{}", i))])
+                .collect(),
+        ))
+    }
+
+    fn is_unexpect_rows(err: anyhow::Error) -> bool {
+        matches!(err.downcast_ref(), Some(RDBCError::UnexpectRows))
+    }
+
+    #[async_std::test]
+    async fn test_fetch() {
+        let mut db = open(1);
+
+        let row: Row = db.fetch_one("SELECT", vec![]).await.unwrap();
+        assert_eq!(row.get_as::<i64, _>("id").unwrap(), 0);
+
+        let row: Option<Row> = db.fetch_optional("SELECT", vec![]).await.unwrap();
+        assert!(row.is_some());
+
+        let id: i64 = db.fetch_scalar("SELECT", vec![]).await.unwrap();
+        assert_eq!(id, 0);
+
+        let mut db = open(3);
+
+        let rows: Vec<Row> = db.fetch_all("SELECT", vec![]).await.unwrap();
+        assert_eq!(rows.len(), 3);
+
+        let mut tx = db.begin().await.unwrap();
+        let rows: Vec<Row> = tx.fetch_all("SELECT", vec![]).await.unwrap();
+        assert_eq!(rows.len(), 3);
+    }
+
+    #[async_std::test]
+    async fn test_query_options() {
+        let table = MockTable::new(&["id"], vec![]);
+        let options = table.query_options.clone();
+
+        let mut db = mock::open(table);
+        let mut stmt = db.prepare("SELECT").await.unwrap();
+
+        stmt.set_fetch_size(Some(100));
+        stmt.set_cursor_mode(CursorMode::Server);
+
+        stmt.query(vec![]).await.unwrap();
+
+        assert_eq!(
+            *options.lock().unwrap(),
+            Some(QueryOptions {
+                fetch_size: Some(100),
+                cursor_mode: CursorMode::Server
+            })
+        );
+    }
+
+    #[async_std::test]
+    async fn test_fetch_unexpect_rows() {
+        let mut db = open(0);
+
+        assert!(is_unexpect_rows(
+            db.fetch_one::<_, Row>("SELECT", vec![]).await.unwrap_err()
+        ));
+        assert!(db
+            .fetch_optional::<_, Row>("SELECT", vec![])
+            .await
             .unwrap()
-            .query(args, async_driver.callback());
+            .is_none());
+        assert!(is_unexpect_rows(
+            db.fetch_scalar::<_, i64>("SELECT", vec![])
+                .await
+                .unwrap_err()
+        ));
+
+        let mut db = open(2);
+
+        let mut stmt = db.prepare("SELECT").await.unwrap();
 
-        Ok(Rows::new(async_driver.await?, self.clone()))
+        assert!(is_unexpect_rows(
+            stmt.fetch_one::<Row>(vec![]).await.unwrap_err()
+        ));
+        assert!(is_unexpect_rows(
+            stmt.fetch_optional::<Row>(vec![]).await.unwrap_err()
+        ));
+        assert!(is_unexpect_rows(
+            stmt.fetch_scalar::<i64>(vec![]).await.unwrap_err()
+        ));
     }
 }
@@ -0,0 +1,121 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use anyhow::Result;
+use futures::{stream::BoxStream, Stream, StreamExt};
+
+use super::{ConnectionPool, FromRow, Row, Rows};
+
+/// [`Stream`] of the remaining rows of [`Rows`], each row read with [`FromRow`].
+///
+/// Created by [`Rows::into_stream`] or [`Rows::into_stream_as`].
+pub struct RowStream<T> {
+    inner: BoxStream<'static, Result<T>>,
+}
+
+impl<T> Stream for RowStream<T> {
+    type Item = Result<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.poll_next_unpin(cx)
+    }
+}
+
+impl<DB> Rows<DB>
+where
+    DB: ConnectionPool + Sync + Send + 'static,
+{
+    /// Convert into a stream of owned [`Row`] read with [`Rows::next_row`].
+    pub fn into_stream(self) -> RowStream<Row> {
+        let inner = futures::stream::try_unfold(self, |mut rows| async move {
+            Ok(rows.next_row().await?.map(|row| (row, rows)))
+        });
+
+        RowStream {
+            inner: inner.boxed(),
+        }
+    }
+
+    /// Convert into a stream of `T`, the stream ends after the first error.
+    pub fn into_stream_as<T>(self) -> RowStream<T>
+    where
+        T: FromRow + Send + 'static,
+    {
+        let inner = futures::stream::try_unfold(self, |mut rows| async move {
+            if rows.next().await? {
+                let value = T::from_row(&mut rows).await?;
+
+                Ok(Some((value, rows)))
+            } else {
+                Ok(None)
+            }
+        });
+
+        RowStream {
+            inner: inner.boxed(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{StreamExt, TryStreamExt};
+    use rdbc_derive::FromRow;
+
+    use crate::{
+        driver::{
+            mock::{self, MockTable},
+            ArgValue,
+        },
+        Preparable,
+    };
+
+    #[derive(FromRow, Debug, PartialEq)]
+    struct Record {
+        id: i64,
+    }
+
+    #[async_std::test]
+    async fn test_into_stream() {
+        let mut db = mock::open(MockTable::new(
+            &["id"],
+            (0..5).map(|i| vec![ArgValue::I64(i)]).collect(),
+        ));
+
+        let mut stmt = db.prepare("SELECT id FROM t").await.unwrap();
+
+        let rows = stmt.query(vec![]).await.unwrap();
+
+        let ids: Vec<i64> = rows
+            .into_stream()
+            .map_ok(|row| match &row.values()[0] {
+                Some(ArgValue::I64(id)) => *id,
+                value => panic!("unexpect {:?}", value),
+            })
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(ids, vec![0, 1, 2, 3, 4]);
+
+        let rows = stmt.query(vec![]).await.unwrap();
+
+        let count = rows
+            .into_stream()
+            .take(2)
+            .try_fold(0, |count, _| async move { Ok(count + 1) })
+            .await
+            .unwrap();
+
+        assert_eq!(count, 2);
+
+        let rows = stmt.query(vec![]).await.unwrap();
+
+        let records: Vec<Record> = rows.into_stream_as().try_collect().await.unwrap();
+
+        assert_eq!(records.len(), 5);
+        assert_eq!(records[4], Record { id: 4 });
+    }
+}
@@ -89,15 +89,17 @@ where
     {
         let async_driver = AsyncDriver::new();
 
-        self.inner
-            .lock()
-            .unwrap()
-            .tx
-            .prepare(query.into(), async_driver.callback());
+        let types = {
+            let mut inner = self.inner.lock().unwrap();
+
+            inner.tx.prepare(query.into(), async_driver.callback());
+
+            inner.db.type_registry()
+        };
 
         let stmt = async_driver.await?;
 
-        Ok(Statement::new(None, None, stmt))
+        Ok(Statement::new(None, None, stmt).with_type_registry(types))
     }
 
     fn driver_name(&self) -> &str {
@@ -2,6 +2,17 @@ pub mod driver;
 
 pub mod future;
 
+mod macros;
+
 pub use future::*;
 
 pub use async_trait::async_trait;
+
+#[doc(hidden)]
+pub use anyhow;
+
+#[cfg(feature = "derive")]
+pub use rdbc_derive::{FromRow, ToArguments};
+
+// Allow derive macros to refer to `::rdbc_rs` inside this crate.
+extern crate self as rdbc_rs;
@@ -0,0 +1,127 @@
+//! Argument list construction macros.
+
+/// Create positional argument list `Vec<Argument>`, numbered from 1.
+///
+/// Values are converted with [`ToArgValue`](crate::driver::ToArgValue), so
+/// the Rust type picks the [`ArgValue`](crate::driver::ArgValue) variant.
+/// Unsuffixed integer literals bind as `ArgValue::I64`, use a suffix such as
+/// `5i32` for other integer types.
+///
+/// ```
+/// use rdbc_rs::{params, driver::{ArgName, ArgValue}};
+///
+/// let args = params![5, "abc", None::<i64>, 5i32];
+///
+/// assert_eq!(args[0].name, ArgName::Offset(1));
+/// assert_eq!(args[0].value, ArgValue::I64(5));
+/// assert_eq!(args[2].value, ArgValue::Null);
+/// assert_eq!(args[3].value, ArgValue::I32(5));
+/// ```
+#[macro_export]
+macro_rules! params {
+    () => {
+        ::std::vec::Vec::<$crate::driver::Argument>::new()
+    };
+    ($($value: expr),+ $(,)?) => {
+        ::std::vec![$($crate::driver::literal_arg_value(
+            ::std::stringify!($value),
+            $crate::driver::ToArgValue::to_arg_value(&$value),
+        )),+]
+            .into_iter()
+            .enumerate()
+            .map(|(index, value)| $crate::driver::Argument {
+                name: $crate::driver::ArgName::Offset(index + 1),
+                value,
+            })
+            .collect::<::std::vec::Vec<$crate::driver::Argument>>()
+    };
+}
+
+/// Create named argument list `Vec<Argument>`.
+///
+/// Values are converted with [`ToArgValue`](crate::driver::ToArgValue), see
+/// [`params!`] for how integer literals bind.
+///
+/// ```
+/// use rdbc_rs::{named_params, driver::{ArgName, ArgValue}};
+///
+/// let args = named_params! { "id" => 5, "name" => "abc" };
+///
+/// assert_eq!(args[0].name, ArgName::String("id".to_owned()));
+/// assert_eq!(args[0].value, ArgValue::I64(5));
+/// assert_eq!(args[1].value, ArgValue::String("abc".to_owned()));
+/// ```
+#[macro_export]
+macro_rules! named_params {
+    () => {
+        ::std::vec::Vec::<$crate::driver::Argument>::new()
+    };
+    ($($name: expr => $value: expr),+ $(,)?) => {
+        ::std::vec![$($crate::driver::Argument {
+            name: ::std::convert::Into::into($name),
+            value: $crate::driver::literal_arg_value(
+                ::std::stringify!($value),
+                $crate::driver::ToArgValue::to_arg_value(&$value),
+            ),
+        }),+]
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::driver::{ArgName, ArgValue, Argument};
+
+    #[test]
+    fn test_params() {
+        let name = String::from("abc");
+
+        assert_eq!(
+            params![5, &name, None::<i64>, vec![1u8]],
+            vec![
+                Argument {
+                    name: ArgName::Offset(1),
+                    value: ArgValue::I64(5)
+                },
+                Argument {
+                    name: ArgName::Offset(2),
+                    value: ArgValue::String(name.clone())
+                },
+                Argument {
+                    name: ArgName::Offset(3),
+                    value: ArgValue::Null
+                },
+                Argument {
+                    name: ArgName::Offset(4),
+                    value: ArgValue::Bytes(vec![1])
+                },
+            ]
+        );
+
+        let id = 5;
+
+        assert_eq!(
+            params![-0x1F, 1_000, 5i32, id]
+                .into_iter()
+                .map(|arg| arg.value)
+                .collect::<Vec<_>>(),
+            vec![
+                ArgValue::I64(-31),
+                ArgValue::I64(1000),
+                ArgValue::I32(5),
+                ArgValue::I32(5)
+            ]
+        );
+
+        assert!(params![].is_empty());
+    }
+
+    #[test]
+    fn test_named_params() {
+        assert_eq!(
+            named_params! { "id" => 5, "name" => "abc", },
+            vec![Argument::new("id", 5i64), Argument::new("name", "abc")]
+        );
+
+        assert!(named_params! {}.is_empty());
+    }
+}